@@ -1,5 +1,6 @@
 use lombok_macros::*;
-use std::{f64::consts::PI, fmt::Debug};
+use lombok_macros_runtime::*;
+use std::{borrow::Cow, f64::consts::PI, fmt::Debug};
 
 #[derive(Clone, Data, Debug, DisplayDebugFormat)]
 struct LombokTest<'a, T: Clone + Debug> {
@@ -198,6 +199,411 @@ struct MultiAttributes {
     complex_field: Vec<String>,
 }
 
+#[derive(FieldNames)]
+struct FieldNamesTest {
+    _user_id: u64,
+    r#_type: String,
+    #[field_name(skip)]
+    _secret: String,
+}
+
+#[derive(FieldAccess)]
+struct FieldAccessTest {
+    port: u16,
+    #[field_access(skip)]
+    _secret: String,
+}
+
+#[derive(Default)]
+struct VisitCollector(Vec<String>);
+
+impl FieldVisitor for VisitCollector {
+    fn visit(&mut self, name: &'static str, value: &dyn Debug) {
+        self.0.push(format!("{name}={value:?}"));
+    }
+
+    fn visit_variant(&mut self, name: &'static str) {
+        self.0.push(name.to_string());
+    }
+}
+
+#[derive(VisitFields)]
+struct VisitTest<T> {
+    #[visit(rename = "id")]
+    user_id: u64,
+    value: T,
+    #[visit(skip)]
+    _secret: String,
+}
+
+#[derive(VisitFields)]
+enum VisitEnumTest {
+    Tuple(#[visit(skip)] u8, i32),
+    Named { flag: bool, visitor: u8 },
+}
+
+#[derive(VisitFields)]
+enum VisitEmptyTest {}
+
+#[derive(Clone, Diff)]
+struct DiffTest {
+    balance: u64,
+    #[debug(skip)]
+    password: String,
+    #[diff(skip)]
+    revision: u32,
+}
+
+#[derive(Value)]
+#[new(pub(crate))]
+struct ValueTest<T: Clone + Debug> {
+    amount: u64,
+    #[get(pub, type(clone))]
+    currency: T,
+    #[debug(skip)]
+    #[new(skip)]
+    revision: u32,
+}
+
+#[derive(Clone, Data)]
+#[data(new, debug, display, eq, hash)]
+#[new(pub(crate))]
+struct DataOptionsTest {
+    #[get(pub)]
+    name: String,
+    #[debug(skip)]
+    #[get(skip)]
+    _token: String,
+}
+
+#[derive(Getter)]
+struct LazyTest(
+    #[get(pub)] Vec<u32>,
+    #[get(pub, lazy = Self::compute_sum)] std::sync::OnceLock<u32>,
+);
+
+impl LazyTest {
+    fn compute_sum(&self) -> u32 {
+        self.get_0().iter().sum()
+    }
+}
+
+#[derive(Data)]
+#[get(pub(crate), computed(area: u64 = self.width * self.height, label: std::collections::HashMap<String, u64> = [("width".to_string(), self.width)].into()))]
+struct ComputedTest {
+    #[get(pub)]
+    width: u64,
+    #[set(pub)]
+    height: u64,
+}
+
+#[derive(Data)]
+struct InteriorTest(
+    #[get(pub, unwrap)]
+    #[set(pub, unwrap)]
+    std::cell::Cell<u8>,
+    #[get(pub, unwrap)]
+    #[set(pub, unwrap)]
+    std::sync::Mutex<String>,
+    #[get(pub, unwrap)]
+    #[set(pub, unwrap)]
+    std::sync::RwLock<Vec<u8>>,
+    std::cell::Cell<String>,
+);
+
+#[derive(Data)]
+#[get(atomic(Acquire))]
+#[set(atomic(Release))]
+struct AtomicTest {
+    #[get(pub)]
+    #[set(pub, atomic(Relaxed), fetch_add)]
+    hits: std::sync::atomic::AtomicU32,
+    #[get(pub)]
+    #[set(pub)]
+    enabled: std::sync::atomic::AtomicBool,
+}
+
+#[derive(Getter)]
+#[get(computed(atomic_hits: u32 = { let atomic = &self.hits; atomic.load(std::sync::atomic::Ordering::Relaxed) }))]
+struct AtomicComputedTest {
+    #[get(pub)]
+    hits: std::sync::atomic::AtomicU32,
+}
+
+#[derive(Getter, Setter)]
+struct AtomicTupleTest(
+    #[get(pub, atomic)]
+    #[set(pub, atomic, fetch_add)]
+    std::sync::atomic::AtomicI64,
+);
+
+trait DelegateConv<A, B> {
+    fn conv(&self, value: A) -> B;
+}
+
+struct DelegateWidener;
+
+impl DelegateConv<u8, u16> for DelegateWidener {
+    fn conv(&self, value: u8) -> u16 {
+        u16::from(value) * 2
+    }
+}
+
+#[derive(Delegate)]
+struct DelegateGenericTraitTest {
+    #[delegate(trait = DelegateConv<u8, u16>, methods(fn conv(&self, value: u8) -> u16))]
+    widener: DelegateWidener,
+}
+
+#[derive(Delegate)]
+struct DelegateTest {
+    #[delegate(methods(len, is_empty, iter_mut))]
+    #[delegate(pub(crate), methods(fn insert(&mut self, key: String, value: u32) -> Option<u32>, fn get(&self, key: &str) -> Option<&u32>))]
+    scores: std::collections::BTreeMap<String, u32>,
+    #[delegate(trait = std::fmt::Write, methods(fn write_str(&mut self, s: &str) -> std::fmt::Result))]
+    log: String,
+}
+
+#[derive(Newtype)]
+struct NewtypeTest<T: Clone>(#[deref(mut)] Vec<T>);
+
+#[derive(Newtype)]
+struct NewtypeNamedTest {
+    #[deref]
+    name: String,
+    _version: u8,
+}
+
+struct MapSourceTest {
+    id: u8,
+    label: &'static str,
+    raw_count: String,
+}
+
+fn parse_map_count(raw: String) -> Result<u32, std::num::ParseIntError> {
+    raw.parse()
+}
+
+#[derive(MapFrom)]
+#[try_map_from(MapSourceTest)]
+struct MapFromTest {
+    id: u16,
+    #[map(rename = "label")]
+    name: String,
+    #[map(rename = "raw_count", try_with = parse_map_count)]
+    count: u32,
+    #[map(skip, default = vec![1])]
+    history: Vec<u8>,
+}
+
+#[derive(New, SmartDefault)]
+struct SmartDefaultTest<T: Clone>(
+    T,
+    #[new(skip)]
+    #[default(Some(7))]
+    Option<u8>,
+);
+
+#[derive(Debug, PartialEq, SmartDefault)]
+enum SmartDefaultEnumTest {
+    #[default]
+    Ready(#[default("ok")] &'static str),
+    _Failed,
+}
+
+#[derive(CustomDebug, FieldNames, serde::Serialize)]
+#[lombok(serde_names)]
+struct SerdeNamesTest {
+    #[serde(rename = "displayName")]
+    display_name: String,
+    #[serde(skip_serializing)]
+    cache: Vec<u8>,
+}
+
+#[derive(CustomDebug, FieldNames, serde::Serialize)]
+#[lombok(serde_names)]
+#[serde(rename_all = "kebab-case")]
+struct SerdeRenameAllTest {
+    retry_count: u8,
+    #[serde(rename(serialize = "TTL"))]
+    time_to_live: u32,
+}
+
+#[derive(Properties)]
+struct PropertiesTest {
+    #[prop(rename = "app.name")]
+    name: String,
+    #[prop(default)]
+    workers: u8,
+    ratio: Option<f32>,
+}
+
+#[derive(Debug, FromEnv)]
+#[env(prefix = "LOMBOK_DEBUG_")]
+struct FromEnvTest {
+    #[env(default = "debug")]
+    name: String,
+    #[env(name = "THREADS")]
+    workers: usize,
+    timeout: Option<u64>,
+    #[env(default = "0")]
+    errors: u32,
+    #[env(default = -5)]
+    offset: i32,
+    #[env(default = true)]
+    verbose: bool,
+}
+
+#[derive(Debug, FromEnv)]
+struct OptionalFromEnvTest {
+    #[env(name = "LOMBOK_DEBUG_OPTIONAL")]
+    _optional: Option<String>,
+}
+
+#[derive(GetterTrait)]
+#[getter_trait(name = "GetterTraitGenericView")]
+struct GetterTraitGenericTest<'a, T: Copy> {
+    name: &'a str,
+    #[get(type(copy))]
+    value: T,
+    #[get(unwrap)]
+    lock: std::sync::Mutex<u8>,
+}
+
+#[derive(GetterTrait)]
+struct GetterTraitTest {
+    label: String,
+    #[get(type(clone))]
+    tags: Vec<String>,
+    #[get(unwrap)]
+    hits: std::cell::RefCell<u32>,
+    #[get(skip)]
+    _hidden: bool,
+}
+
+#[derive(View)]
+#[view(name = "HTTPViewTestSummary", fields(host))]
+#[view(name = "ViewTestCounters", fields(requests, errors), mut)]
+struct ViewTest {
+    host: String,
+    requests: u64,
+    errors: u64,
+}
+
+#[derive(View)]
+#[view(name = "ViewGenericTestLabel", fields(label))]
+#[view(name = "ViewGenericTestEdit", fields(items), mut)]
+struct ViewGenericTest<'a, T: Clone, const N: usize> {
+    label: &'a str,
+    items: [T; N],
+}
+
+#[derive(Data, Debug, Clone)]
+#[get_mut(split)]
+struct SplitMutTest<'a, T: Clone>(Vec<T>, #[get_mut(skip)] &'a str, usize);
+
+#[derive(Data, Debug, Clone, Patch)]
+#[patch(name = "PatchTestUpdate", derive(Debug, Clone))]
+struct PatchTest {
+    r#type: String,
+    #[set(type(Into<String>))]
+    label: String,
+    limit: Option<u32>,
+    #[set(skip)]
+    version: u32,
+}
+
+#[derive(Setter, Patch)]
+#[patch(derive(Debug, Clone))]
+struct PatchGenericTest<'a, T: Clone, U: Copy> {
+    name: &'a str,
+    values: Vec<T>,
+    #[set(skip)]
+    marker: U,
+}
+
+#[derive(Debug, Merge)]
+struct MergeTest<T: lombok_macros_runtime::Merge>(
+    Option<u8>,
+    #[merge(append)] std::collections::BTreeSet<u8>,
+    #[merge(nested)] T,
+);
+
+#[derive(Debug, Merge)]
+struct MergeInnerTest {
+    #[merge(overwrite)]
+    value: Option<u8>,
+}
+
+#[derive(Debug, TypedBuilder)]
+struct TypedBuilderTest<'a, T: Clone + Default> {
+    #[builder(pub(crate), type(AsRef<str>))]
+    name: String,
+    source: &'a str,
+    #[builder(default)]
+    value: T,
+    #[builder(skip, default = 3)]
+    attempts: u8,
+}
+
+#[derive(Debug, PartialEq, New)]
+struct NewTupleFromTest {
+    x: u32,
+    y: u32,
+}
+
+impl From<(u32, u32)> for NewTupleFromTest {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+#[derive(Debug, Clone, Data, New, TypedBuilder)]
+struct FlattenInnerTest {
+    tag: String,
+    #[builder(default = 9)]
+    level: u8,
+}
+
+#[derive(Getter)]
+struct FlattenDerefTest {
+    #[get(pub, flatten(tag: &String))]
+    inner: FlattenInnerTest,
+    items: Vec<u8>,
+}
+
+impl std::ops::Deref for FlattenDerefTest {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+#[derive(Debug, Clone, Data, New)]
+struct FlattenTest(
+    u32,
+    #[new(flatten(tag: String, level: u8))]
+    #[get(flatten(tag: &String, level: &u8))]
+    FlattenInnerTest,
+);
+
+#[derive(Debug, TypedBuilder)]
+struct FlattenBuilderTest {
+    #[builder(flatten, default = FlattenInnerTest::new("default".to_string(), 0))]
+    inner: FlattenInnerTest,
+    #[builder(flatten)]
+    required: FlattenInnerTest,
+}
+
+#[derive(Data, Debug)]
+struct CowTupleTest<'a>(Cow<'a, str>, #[get(pub, into_owned)] Cow<'a, [i32]>);
+
+#[derive(Debug, TypedBuilder)]
+struct CowBuilderTest<'a> {
+    name: Cow<'a, str>,
+}
+
 fn main() {
     let mut data: LombokTest<usize> = LombokTest {
         list: Vec::new(),
@@ -393,4 +799,380 @@ fn main() {
     let copied_count: u64 = copy_test.get_count();
     assert!(copied_flag);
     assert_eq!(copied_count, 1000);
+    assert_eq!(FieldNamesTest::FIELD__USER_ID, "_user_id");
+    assert_eq!(FieldNamesTest::field_names(), &["_user_id", "_type"]);
+    assert_eq!(FieldNamesTestField::UserId.as_str(), "_user_id");
+    assert_eq!(FieldNamesTestField::ALL.len(), 2);
+    assert_eq!(
+        "_type".parse::<FieldNamesTestField>(),
+        Ok(FieldNamesTestField::Type)
+    );
+    let mut field_access: FieldAccessTest = FieldAccessTest {
+        port: 80,
+        _secret: "hidden".to_string(),
+    };
+    assert!(field_access.get_field("_secret").is_none());
+    assert_eq!(field_access.set_field("port", Box::new(8080u16)), Ok(()));
+    assert_eq!(field_access.port, 8080);
+    assert_eq!(
+        field_access.set_field("port", Box::new("8080")),
+        Err(FieldError::TypeMismatch {
+            field: "port".to_string(),
+            expected: std::any::type_name::<u16>(),
+        })
+    );
+    assert_eq!(field_access.field_names(), &["port"]);
+    let mut visit_collector: VisitCollector = VisitCollector::default();
+    VisitTest {
+        user_id: 7,
+        value: "v",
+        _secret: "hidden".to_string(),
+    }
+    .visit_fields(&mut visit_collector);
+    let visit_tuple: VisitEnumTest = VisitEnumTest::Tuple(1, 2);
+    visit_tuple.visit_fields(&mut visit_collector);
+    if let VisitEnumTest::Tuple(code, _) = visit_tuple {
+        assert_eq!(code, 1);
+    }
+    VisitEnumTest::Named {
+        flag: true,
+        visitor: 3,
+    }
+    .visit_fields(&mut visit_collector);
+    let _visit_empty: fn(&VisitEmptyTest, &mut VisitCollector) = VisitEmptyTest::visit_fields;
+    assert_eq!(
+        visit_collector.0,
+        vec![
+            "id=7",
+            "value=\"v\"",
+            "Tuple",
+            "1=2",
+            "Named",
+            "flag=true",
+            "visitor=3"
+        ]
+    );
+    let diff_old: DiffTest = DiffTest {
+        balance: 1,
+        password: "old".to_string(),
+        revision: 1,
+    };
+    let mut diff_new: DiffTest = diff_old.clone();
+    diff_new.balance = 2;
+    diff_new.password = "new".to_string();
+    diff_new.revision = 2;
+    let changes: Vec<FieldChange> = diff_old.diff(&diff_new);
+    assert_eq!(
+        changes,
+        vec![
+            FieldChange::new("balance", "1".to_string(), "2".to_string()),
+            FieldChange::redacted("password"),
+        ]
+    );
+    assert!(!format!("{changes:?}").contains("new\""));
+    assert_eq!(diff_new.revision, 2);
+    let value_test: ValueTest<String> = ValueTest::new(5, "EUR".to_string());
+    assert_eq!(*value_test.get_amount(), 5);
+    assert_eq!(value_test.get_currency(), "EUR");
+    assert_eq!(*value_test.get_revision(), 0);
+    assert_eq!(
+        format!("{value_test:?}"),
+        "ValueTest { amount: 5, currency: \"EUR\" }"
+    );
+    assert_eq!(value_test, ValueTest::new(5, "EUR".to_string()));
+    assert_ne!(value_test, ValueTest::new(6, "EUR".to_string()));
+    let data_options: DataOptionsTest =
+        DataOptionsTest::new("alice".to_string(), "token".to_string());
+    assert_eq!(data_options.get_name(), "alice");
+    assert_eq!(
+        data_options.to_string(),
+        "DataOptionsTest { name: \"alice\" }"
+    );
+    assert_eq!(data_options, data_options.clone());
+    let data_options_set: std::collections::HashSet<DataOptionsTest> =
+        [data_options.clone(), data_options.clone()]
+            .into_iter()
+            .collect();
+    assert_eq!(data_options_set.len(), 1);
+    let lazy_test: LazyTest = LazyTest(vec![1, 2, 3], std::sync::OnceLock::new());
+    assert_eq!(*lazy_test.get_1(), 6);
+    assert_eq!(lazy_test.1.get(), Some(&6));
+    let mut computed_test: ComputedTest = ComputedTest {
+        width: 3,
+        height: 4,
+    };
+    assert_eq!(computed_test.get_area(), 12);
+    computed_test.set_height(5);
+    assert_eq!(computed_test.get_area(), 15);
+    assert_eq!(computed_test.get_label().get("width"), Some(&3));
+    let interior: InteriorTest = InteriorTest(
+        std::cell::Cell::new(1),
+        std::sync::Mutex::new("a".to_string()),
+        std::sync::RwLock::new(vec![]),
+        std::cell::Cell::new(String::new()),
+    );
+    interior.set_0(2).set_1("b".to_string()).set_2(vec![3]);
+    assert_eq!(interior.get_0(), 2);
+    assert_eq!(*interior.get_1(), "b");
+    assert!(interior.try_set_1("c".to_string()).is_ok());
+    assert_eq!(*interior.try_get_1().unwrap(), "c");
+    assert_eq!(*interior.get_2(), vec![3]);
+    assert!(interior.try_get_2().is_ok());
+    assert_eq!(interior.get_3().take(), "");
+    let atomic: AtomicTest = AtomicTest {
+        hits: std::sync::atomic::AtomicU32::new(0),
+        enabled: std::sync::atomic::AtomicBool::new(false),
+    };
+    atomic.set_hits(2).set_enabled(true);
+    assert_eq!(atomic.increment_hits(), 2);
+    assert_eq!(atomic.fetch_add_hits(3), 3);
+    assert_eq!(atomic.get_hits(), 6);
+    assert!(atomic.get_enabled());
+    let atomic_computed: AtomicComputedTest = AtomicComputedTest {
+        hits: std::sync::atomic::AtomicU32::new(4),
+    };
+    let atomic_computed_hits: &std::sync::atomic::AtomicU32 = atomic_computed.get_hits();
+    atomic_computed_hits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    assert_eq!(atomic_computed.get_atomic_hits(), 5);
+    let atomic_tuple: AtomicTupleTest = AtomicTupleTest(std::sync::atomic::AtomicI64::new(-1));
+    atomic_tuple.increment_0();
+    atomic_tuple.set_0(atomic_tuple.get_0() + 5);
+    assert_eq!(atomic_tuple.fetch_add_0(1), 5);
+    assert_eq!(atomic_tuple.get_0(), 6);
+    let mut delegate: DelegateTest = DelegateTest {
+        scores: std::collections::BTreeMap::new(),
+        log: String::new(),
+    };
+    assert!(delegate.is_empty());
+    delegate.insert("ada".to_string(), 1);
+    for (_, score) in delegate.iter_mut() {
+        *score += 1;
+    }
+    assert_eq!(delegate.get("ada"), Some(&2));
+    assert_eq!(delegate.len(), 1);
+    std::fmt::Write::write_fmt(&mut delegate, format_args!("{}", 42)).unwrap();
+    assert_eq!(delegate.log, "42");
+    let delegate_generic: DelegateGenericTraitTest = DelegateGenericTraitTest {
+        widener: DelegateWidener,
+    };
+    assert_eq!(delegate_generic.conv(4), 8);
+    let mut newtype: NewtypeTest<u8> = NewtypeTest::from(vec![1]);
+    newtype.push(2);
+    assert_eq!(newtype.as_ref(), &vec![1, 2]);
+    assert_eq!(newtype.into_inner(), vec![1, 2]);
+    let newtype_named: NewtypeNamedTest = "lombok".to_string().into();
+    assert_eq!(newtype_named.len(), 6);
+    assert_eq!(
+        std::borrow::Borrow::<String>::borrow(&newtype_named),
+        "lombok"
+    );
+    let map_from: MapFromTest = MapFromTest::try_from(MapSourceTest {
+        id: 1,
+        label: "map",
+        raw_count: "3".to_string(),
+    })
+    .unwrap();
+    assert_eq!(map_from.id, 1);
+    assert_eq!(map_from.name, "map");
+    assert_eq!(map_from.count, 3);
+    assert_eq!(map_from.history, vec![1]);
+    let smart_default: SmartDefaultTest<String> = SmartDefaultTest::default();
+    assert_eq!(smart_default.0, "");
+    assert_eq!(smart_default.1, Some(7));
+    assert_eq!(SmartDefaultTest::new(1).1, Some(7));
+    assert_eq!(
+        SmartDefaultEnumTest::default(),
+        SmartDefaultEnumTest::Ready("ok")
+    );
+    let serde_names: SerdeNamesTest = SerdeNamesTest {
+        display_name: "ada".to_string(),
+        cache: vec![1],
+    };
+    assert_eq!(
+        format!("{serde_names:?}"),
+        "SerdeNamesTest { displayName: \"ada\" }"
+    );
+    assert_eq!(SerdeNamesTest::FIELD_DISPLAY_NAME, "displayName");
+    assert_eq!(SerdeNamesTest::FIELD_CACHE, "cache");
+    assert_eq!(serde_names.cache, vec![1]);
+    let serde_rename_all: SerdeRenameAllTest = SerdeRenameAllTest {
+        retry_count: 2,
+        time_to_live: 30,
+    };
+    assert_eq!(
+        format!("{serde_rename_all:?}"),
+        "SerdeRenameAllTest { retry-count: 2, TTL: 30 }"
+    );
+    assert_eq!(SerdeRenameAllTest::field_names(), &["retry-count", "TTL"]);
+    let properties_map: std::collections::HashMap<String, String> = [
+        ("app.name".to_string(), "lombok".to_string()),
+        ("ratio".to_string(), "0.5".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let properties: PropertiesTest = PropertiesTest::from_map(&properties_map).unwrap();
+    assert_eq!(properties.name, "lombok");
+    assert_eq!(properties.workers, 0);
+    assert_eq!(properties.ratio, Some(0.5));
+    assert_eq!(properties.to_map().len(), 3);
+    assert!(PropertiesTest::from_map(&std::collections::HashMap::new()).is_err());
+    let from_env_error: lombok_macros_runtime::EnvError = FromEnvTest::from_env().unwrap_err();
+    assert_eq!(from_env_error.missing(), vec!["LOMBOK_DEBUG_THREADS"]);
+    unsafe {
+        std::env::set_var("LOMBOK_DEBUG_THREADS", "4");
+        std::env::set_var("LOMBOK_DEBUG_TIMEOUT", "soon");
+    }
+    let from_env_error: lombok_macros_runtime::EnvError = FromEnvTest::from_env().unwrap_err();
+    assert_eq!(from_env_error.invalid(), vec!["LOMBOK_DEBUG_TIMEOUT"]);
+    unsafe {
+        std::env::set_var("LOMBOK_DEBUG_TIMEOUT", "30");
+    }
+    let from_env: FromEnvTest = FromEnvTest::from_env().unwrap();
+    assert_eq!(from_env.name, "debug");
+    assert_eq!(from_env.workers, 4);
+    assert_eq!(from_env.timeout, Some(30));
+    assert_eq!(from_env.errors, 0);
+    assert_eq!(from_env.offset, -5);
+    assert!(from_env.verbose);
+    assert!(OptionalFromEnvTest::from_env().is_ok());
+    let getter_trait: GetterTraitTest = GetterTraitTest {
+        label: "view".to_string(),
+        tags: vec!["a".to_string()],
+        hits: std::cell::RefCell::new(2),
+        _hidden: true,
+    };
+    let getter_trait_view: &dyn GetterTraitTestGetters = &getter_trait;
+    assert_eq!(getter_trait_view.get_label(), "view");
+    assert_eq!(getter_trait_view.get_tags(), vec!["a".to_string()]);
+    assert_eq!(*getter_trait_view.get_hits(), 2);
+    let getter_trait_generic: GetterTraitGenericTest<i8> = GetterTraitGenericTest {
+        name: "generic",
+        value: -1,
+        lock: std::sync::Mutex::new(5),
+    };
+    let getter_trait_generic_view: &dyn GetterTraitGenericView<'_, i8> = &getter_trait_generic;
+    assert_eq!(*getter_trait_generic_view.get_name(), "generic");
+    assert_eq!(getter_trait_generic_view.get_value(), -1);
+    assert_eq!(*getter_trait_generic_view.get_lock(), 5);
+    assert!(getter_trait_generic_view.try_get_lock().is_ok());
+    let mut view_test: ViewTest = ViewTest {
+        host: "localhost".to_string(),
+        requests: 0,
+        errors: 0,
+    };
+    let view_test_counters: ViewTestCounters<'_> = view_test.as_view_test_counters_mut();
+    *view_test_counters.requests += 2;
+    *view_test_counters.errors += 1;
+    assert_eq!(view_test.as_http_view_test_summary().host, "localhost");
+    assert_eq!((view_test.requests, view_test.errors), (2, 1));
+    let mut view_generic: ViewGenericTest<u8, 2> = ViewGenericTest {
+        label: "generic",
+        items: [1, 2],
+    };
+    view_generic.as_view_generic_test_edit_mut().items[0] = 3;
+    assert_eq!(*view_generic.as_view_generic_test_label().label, "generic");
+    assert_eq!(view_generic.items, [3, 2]);
+    let mut split_mut: SplitMutTest<u8> = SplitMutTest(vec![1], "split", 0);
+    let split_mut_borrow: SplitMutTestMut<'_, '_, u8> = split_mut.split_mut();
+    split_mut_borrow.0.push(2);
+    *split_mut_borrow.1 = split_mut_borrow.0.len();
+    assert_eq!(*split_mut.get_2(), 2);
+    assert_eq!(*split_mut.get_1(), "split");
+    let mut patch_test: PatchTest = PatchTest {
+        r#type: "a".to_string(),
+        label: "old".to_string(),
+        limit: None,
+        version: 1,
+    };
+    let patch_update: PatchTestUpdate = PatchTestUpdate {
+        r#type: Some("b".to_string()),
+        label: Some("new".to_string()),
+        limit: Some(Some(5)),
+    };
+    patch_test
+        .apply(patch_update.clone())
+        .apply(PatchTestUpdate::default());
+    assert_eq!(patch_test.get_type(), "b");
+    assert_eq!(patch_test.get_label(), "new");
+    assert_eq!(patch_test.limit, Some(5));
+    assert_eq!(*patch_test.get_version(), 1);
+    let mut patch_generic: PatchGenericTest<u8, char> = PatchGenericTest {
+        name: "generic",
+        values: vec![1],
+        marker: 'x',
+    };
+    patch_generic.apply(PatchGenericTestPatch {
+        values: Some(vec![2, 3]),
+        ..Default::default()
+    });
+    assert_eq!(
+        (
+            patch_generic.name,
+            patch_generic.values,
+            patch_generic.marker
+        ),
+        ("generic", vec![2, 3], 'x')
+    );
+    let mut merge_test: MergeTest<MergeInnerTest> = MergeTest(
+        Some(1),
+        [1].into_iter().collect(),
+        MergeInnerTest { value: Some(1) },
+    );
+    lombok_macros_runtime::Merge::merge(
+        &mut merge_test,
+        MergeTest(
+            None,
+            [2].into_iter().collect(),
+            MergeInnerTest { value: None },
+        ),
+    );
+    assert_eq!(merge_test.0, Some(1));
+    assert_eq!(merge_test.1.len(), 2);
+    assert_eq!(merge_test.2.value, None);
+    let typed_builder: TypedBuilderTest<u16> = TypedBuilderTest::builder()
+        .source("cli")
+        .name("typed")
+        .build();
+    assert_eq!(typed_builder.name, "typed");
+    assert_eq!(typed_builder.source, "cli");
+    assert_eq!(typed_builder.value, 0);
+    assert_eq!(typed_builder.attempts, 3);
+    let typed_builder_value: TypedBuilderTest<u16> = TypedBuilderTest::builder()
+        .value(7)
+        .name(String::from("value"))
+        .source("env")
+        .build();
+    assert_eq!(typed_builder_value.value, 7);
+    let flatten: FlattenTest = FlattenTest::new(1, "flat".to_string(), 3);
+    assert_eq!(flatten.get_tag(), "flat");
+    assert_eq!(*flatten.get_level(), 3);
+    assert_eq!(flatten.get_1().get_tag(), "flat");
+    assert_eq!(*flatten.get_0(), 1);
+    let flatten_deref: FlattenDerefTest = FlattenDerefTest {
+        inner: FlattenInnerTest::new("deref".to_string(), 0),
+        items: vec![1, 2],
+    };
+    assert_eq!(flatten_deref.get_tag(), "deref");
+    assert_eq!(flatten_deref.len(), 2);
+    let new_tuple_from: NewTupleFromTest = (2, 4).into();
+    assert_eq!(new_tuple_from, NewTupleFromTest::new(2, 4));
+    let flatten_builder: FlattenBuilderTest = FlattenBuilderTest::builder()
+        .required(|inner| inner.tag("required".to_string()))
+        .build();
+    assert_eq!(flatten_builder.inner.tag, "default");
+    assert_eq!(flatten_builder.required.level, 9);
+    let flatten_builder_inner: FlattenBuilderTest = FlattenBuilderTest::builder()
+        .inner(|inner| inner.level(1).tag("inner".to_string()))
+        .required(|inner| inner.tag("required".to_string()))
+        .build();
+    assert_eq!(flatten_builder_inner.inner.level, 1);
+    let mut cow_tuple: CowTupleTest = CowTupleTest(Cow::Borrowed("cow"), Cow::Borrowed(&[1, 2]));
+    let cow_str: &str = cow_tuple.get_0();
+    assert_eq!(cow_str, "cow");
+    let cow_owned: Vec<i32> = cow_tuple.get_1();
+    assert_eq!(cow_owned, vec![1, 2]);
+    cow_tuple.set_0(String::from("owned")).set_1(vec![3]);
+    assert_eq!(cow_tuple.get_0(), "owned");
+    let cow_builder: CowBuilderTest = CowBuilderTest::builder().name("built").build();
+    assert_eq!(cow_builder.name, "built");
 }
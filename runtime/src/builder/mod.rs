@@ -0,0 +1,4 @@
+mod r#struct;
+mod r#trait;
+
+pub use {r#struct::*, r#trait::*};
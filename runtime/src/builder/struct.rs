@@ -0,0 +1,10 @@
+/// Marks a required field of a typed builder that has not been set yet.
+///
+/// Builders generated by `#[derive(TypedBuilder)]` carry one marker per required field,
+/// and `build()` only exists once every marker is `BuilderSet`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BuilderUnset;
+
+/// Marks a required field of a typed builder that holds its value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BuilderSet<T>(pub T);
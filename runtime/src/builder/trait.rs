@@ -0,0 +1,27 @@
+/// Provides the typed builder of a struct, implemented by `#[derive(TypedBuilder)]`.
+///
+/// A field marked `#[builder(flatten)]` uses this trait to start the builder of its type.
+pub trait TypedBuild {
+    /// The builder with every required field unset.
+    type Builder;
+
+    /// Creates the builder with every required field unset.
+    ///
+    /// # Returns
+    /// - `Self::Builder` - The new builder.
+    fn builder() -> Self::Builder;
+}
+
+/// Finishes a typed builder whose required fields are all set, implemented by `#[derive(TypedBuilder)]`.
+///
+/// A field marked `#[builder(flatten)]` uses this trait to build the value of its type.
+pub trait Build {
+    /// The struct built by the builder.
+    type Output;
+
+    /// Builds the struct.
+    ///
+    /// # Returns
+    /// - `Self::Output` - The built struct.
+    fn build(self) -> Self::Output;
+}
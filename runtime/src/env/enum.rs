@@ -0,0 +1,19 @@
+/// Represents a single problem found while reading a struct from environment variables.
+///
+/// # Variants
+/// - `Missing` - A required variable is not set.
+/// - `Invalid` - A variable could not be parsed into the field's type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnvVarError {
+    /// A required variable is not set.
+    Missing(String),
+    /// A variable could not be parsed into the field's type.
+    Invalid {
+        /// The name of the variable.
+        name: String,
+        /// The value that failed to parse.
+        value: String,
+        /// The message of the parse error.
+        message: String,
+    },
+}
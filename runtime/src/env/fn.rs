@@ -0,0 +1,69 @@
+use crate::*;
+
+/// Reads and parses an optional environment variable.
+///
+/// # Arguments
+/// - `&str` - The name of the variable.
+/// - `Option<&str>` - The value parsed when the variable is not set.
+/// - `&mut Vec<EnvVarError>` - The problems found so far, extended when the value is invalid.
+///
+/// # Returns
+/// - `Option<T>` - The parsed value, or `None` when it is unset without a default or invalid.
+pub fn parse_optional_env_var<T>(
+    name: &str,
+    default: Option<&str>,
+    errors: &mut Vec<EnvVarError>,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value: String = match std::env::var(name) {
+        Ok(value) => value,
+        Err(VarError::NotPresent) => default?.to_string(),
+        Err(VarError::NotUnicode(value)) => {
+            errors.push(EnvVarError::Invalid {
+                name: name.to_string(),
+                value: value.to_string_lossy().into_owned(),
+                message: "value is not valid unicode".to_string(),
+            });
+            return None;
+        }
+    };
+    match value.parse::<T>() {
+        Ok(parsed) => Some(parsed),
+        Err(error) => {
+            errors.push(EnvVarError::Invalid {
+                name: name.to_string(),
+                value,
+                message: error.to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// Reads and parses a required environment variable.
+///
+/// # Arguments
+/// - `&str` - The name of the variable.
+/// - `Option<&str>` - The value parsed when the variable is not set.
+/// - `&mut Vec<EnvVarError>` - The problems found so far, extended when the value is missing or invalid.
+///
+/// # Returns
+/// - `Option<T>` - The parsed value, or `None` when a problem was recorded.
+pub fn parse_env_var<T>(
+    name: &str,
+    default: Option<&str>,
+    errors: &mut Vec<EnvVarError>,
+) -> Option<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if default.is_none() && std::env::var_os(name).is_none() {
+        errors.push(EnvVarError::Missing(name.to_string()));
+        return None;
+    }
+    parse_optional_env_var(name, default, errors)
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+impl EnvError {
+    /// Creates a new `EnvError`.
+    ///
+    /// # Arguments
+    /// - `Vec<EnvVarError>` - The problems found while reading the variables.
+    ///
+    /// # Returns
+    /// - `EnvError` - The created error.
+    #[inline(always)]
+    pub fn new(errors: Vec<EnvVarError>) -> Self {
+        Self { errors }
+    }
+
+    /// Gets the names of the required variables that are not set.
+    ///
+    /// # Returns
+    /// - `Vec<&str>` - The names in field declaration order.
+    pub fn missing(&self) -> Vec<&str> {
+        self.errors
+            .iter()
+            .filter_map(|error| match error {
+                EnvVarError::Missing(name) => Some(name.as_str()),
+                EnvVarError::Invalid { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Gets the names of the variables whose values could not be parsed.
+    ///
+    /// # Returns
+    /// - `Vec<&str>` - The names in field declaration order.
+    pub fn invalid(&self) -> Vec<&str> {
+        self.errors
+            .iter()
+            .filter_map(|error| match error {
+                EnvVarError::Missing(_) => None,
+                EnvVarError::Invalid { name, .. } => Some(name.as_str()),
+            })
+            .collect()
+    }
+}
+
+/// Implementation of Display trait for EnvVarError enum.
+impl Display for EnvVarError {
+    /// Formats the `EnvVarError` into a human readable message.
+    ///
+    /// # Arguments
+    /// - `self` - The reference to the `EnvVarError` instance being formatted.
+    /// - `Formatter<'_>` - The formatter to write the message to.
+    ///
+    /// # Returns
+    /// - `Result` - indicating success or failure of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvVarError::Missing(name) => write!(f, "Missing environment variable: {name}"),
+            EnvVarError::Invalid {
+                name,
+                value,
+                message,
+            } => write!(
+                f,
+                "Invalid value '{value}' for environment variable '{name}': {message}"
+            ),
+        }
+    }
+}
+
+/// Implementation of Display trait for EnvError struct.
+impl Display for EnvError {
+    /// Formats the `EnvError` as its problems separated by `; `.
+    ///
+    /// # Arguments
+    /// - `self` - The reference to the `EnvError` instance being formatted.
+    /// - `Formatter<'_>` - The formatter to write the message to.
+    ///
+    /// # Returns
+    /// - `Result` - indicating success or failure of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for EnvError {}
@@ -0,0 +1,6 @@
+mod r#enum;
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub use {r#enum::*, r#fn::*, r#struct::*};
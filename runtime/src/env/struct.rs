@@ -0,0 +1,11 @@
+use crate::*;
+
+/// Represents every problem found while reading a struct from environment variables.
+///
+/// `from_env` keeps reading after the first failure, so a single `EnvError`
+/// reports all missing and invalid variables at once.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EnvError {
+    /// The problems in field declaration order.
+    pub errors: Vec<EnvVarError>,
+}
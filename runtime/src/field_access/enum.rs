@@ -0,0 +1,17 @@
+/// Represents an error raised while accessing a field by name.
+///
+/// # Variants
+/// - `UnknownField` - No accessible field exists with the given name.
+/// - `TypeMismatch` - The provided value does not have the field's type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FieldError {
+    /// No accessible field exists with the given name.
+    UnknownField(String),
+    /// The provided value does not have the field's type.
+    TypeMismatch {
+        /// The name of the field being written.
+        field: String,
+        /// The type name the field expects.
+        expected: &'static str,
+    },
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+/// Implementation of Display trait for FieldError enum.
+impl Display for FieldError {
+    /// Formats the `FieldError` into a human readable message.
+    ///
+    /// # Arguments
+    /// - `self` - The reference to the `FieldError` instance being formatted.
+    /// - `Formatter<'_>` - The formatter to write the message to.
+    ///
+    /// # Returns
+    /// - `Result` - indicating success or failure of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::UnknownField(field) => write!(f, "Unknown field: {field}"),
+            FieldError::TypeMismatch { field, expected } => {
+                write!(f, "Type mismatch for field '{field}': expected {expected}")
+            }
+        }
+    }
+}
+
+impl Error for FieldError {}
@@ -0,0 +1,6 @@
+mod r#enum;
+mod r#impl;
+mod r#trait;
+
+pub use r#enum::*;
+pub use r#trait::*;
@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Provides dynamic read and write access to struct fields by name.
+///
+/// Implementations are generated by `#[derive(FieldAccess)]`. Only fields whose
+/// types are `'static` can be exposed, since values are passed as `dyn Any`.
+pub trait FieldAccess {
+    /// Returns a reference to the field with the given name.
+    ///
+    /// # Arguments
+    /// - `&str` - The name of the field.
+    ///
+    /// # Returns
+    /// - `Option<&dyn Any>` - The field value, or `None` if no such field exists.
+    fn get_field(&self, name: &str) -> Option<&dyn Any>;
+
+    /// Returns a mutable reference to the field with the given name.
+    ///
+    /// # Arguments
+    /// - `&str` - The name of the field.
+    ///
+    /// # Returns
+    /// - `Option<&mut dyn Any>` - The field value, or `None` if no such field exists.
+    fn get_field_mut(&mut self, name: &str) -> Option<&mut dyn Any>;
+
+    /// Replaces the value of the field with the given name.
+    ///
+    /// # Arguments
+    /// - `&str` - The name of the field.
+    /// - `Box<dyn Any>` - The new value, which must have the field's exact type.
+    ///
+    /// # Returns
+    /// - `Result<(), FieldError>` - Ok if the field was updated, or the reason it was not.
+    fn set_field(&mut self, name: &str, value: Box<dyn Any>) -> Result<(), FieldError>;
+
+    /// Returns the names of all accessible fields in declaration order.
+    ///
+    /// # Returns
+    /// - `&'static [&'static str]` - The accessible field names.
+    fn field_names(&self) -> &'static [&'static str];
+}
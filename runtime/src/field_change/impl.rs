@@ -0,0 +1,60 @@
+use crate::*;
+
+impl FieldChange {
+    /// The placeholder used instead of the values of redacted fields.
+    pub const REDACTED: &'static str = "[REDACTED]";
+
+    /// Creates a new `FieldChange`.
+    ///
+    /// # Arguments
+    /// - `&'static str` - The name of the changed field.
+    /// - `String` - The `Debug` rendering of the old value.
+    /// - `String` - The `Debug` rendering of the new value.
+    ///
+    /// # Returns
+    /// - `FieldChange` - The created change.
+    #[inline(always)]
+    pub fn new(field: &'static str, old: String, new: String) -> Self {
+        Self { field, old, new }
+    }
+
+    /// Creates a new `FieldChange` whose values are redacted.
+    ///
+    /// # Arguments
+    /// - `&'static str` - The name of the changed field.
+    ///
+    /// # Returns
+    /// - `FieldChange` - The created change with both values set to `FieldChange::REDACTED`.
+    #[inline(always)]
+    pub fn redacted(field: &'static str) -> Self {
+        Self::new(
+            field,
+            Self::REDACTED.to_string(),
+            Self::REDACTED.to_string(),
+        )
+    }
+
+    /// Checks if the values of this change are redacted.
+    ///
+    /// # Returns
+    /// - `bool` - True if the change was created for a `#[debug(skip)]` field.
+    #[inline(always)]
+    pub fn is_redacted(&self) -> bool {
+        self.old == Self::REDACTED && self.new == Self::REDACTED
+    }
+}
+
+/// Implementation of Display trait for FieldChange struct.
+impl Display for FieldChange {
+    /// Formats the `FieldChange` as `field: old -> new`.
+    ///
+    /// # Arguments
+    /// - `self` - The reference to the `FieldChange` instance being formatted.
+    /// - `Formatter<'_>` - The formatter to write the change to.
+    ///
+    /// # Returns
+    /// - `Result` - indicating success or failure of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.old, self.new)
+    }
+}
@@ -0,0 +1,4 @@
+mod r#impl;
+mod r#struct;
+
+pub use r#struct::*;
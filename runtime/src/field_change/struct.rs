@@ -0,0 +1,13 @@
+/// Represents a single field that differs between two instances of a type.
+///
+/// Values are rendered with `Debug`. Fields marked `#[debug(skip)]` are reported
+/// with both values replaced by `FieldChange::REDACTED`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FieldChange {
+    /// The name of the changed field, or its index for tuple fields.
+    pub field: &'static str,
+    /// The `Debug` rendering of the old value.
+    pub old: String,
+    /// The `Debug` rendering of the new value.
+    pub new: String,
+}
@@ -0,0 +1,3 @@
+mod r#trait;
+
+pub use r#trait::*;
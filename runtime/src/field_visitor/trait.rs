@@ -0,0 +1,20 @@
+use crate::*;
+
+/// Receives the fields of a value, one at a time, without serialization.
+///
+/// Implementations are driven by the `visit_fields` method generated by
+/// `#[derive(VisitFields)]`, so a single visitor can inspect any deriving type.
+pub trait FieldVisitor {
+    /// Visits a single field.
+    ///
+    /// # Arguments
+    /// - `&'static str` - The field name, or its index for tuple fields.
+    /// - `&dyn Debug` - The field value.
+    fn visit(&mut self, name: &'static str, value: &dyn Debug);
+
+    /// Visits the active variant of an enum before its fields.
+    ///
+    /// # Arguments
+    /// - `&'static str` - The variant name.
+    fn visit_variant(&mut self, _name: &'static str) {}
+}
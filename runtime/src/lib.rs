@@ -0,0 +1,25 @@
+//! lombok-macros-runtime
+//!
+//! Runtime traits and types used by the code generated by `lombok-macros`.
+//! Procedural macro crates can only export macros, so every trait or error type
+//! referenced by a generated implementation lives here.
+
+mod builder;
+mod env;
+mod field_access;
+mod field_change;
+mod field_visitor;
+mod merge;
+mod property;
+
+pub use {
+    builder::*, env::*, field_access::*, field_change::*, field_visitor::*, merge::*, property::*,
+};
+
+use std::{
+    any::Any,
+    env::VarError,
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
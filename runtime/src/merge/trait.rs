@@ -0,0 +1,26 @@
+/// Combines two values of the same type, with the other value taking precedence.
+///
+/// Implementations are generated by `#[derive(Merge)]`, which applies a strategy per field.
+/// Layered configuration is built by merging from the lowest to the highest priority layer.
+pub trait Merge {
+    /// Merges `other` into `self`.
+    ///
+    /// # Arguments
+    /// - `Self` - The value whose fields take precedence according to each field's strategy.
+    fn merge(&mut self, other: Self);
+
+    /// Merges `other` into `self` and returns the result.
+    ///
+    /// # Arguments
+    /// - `Self` - The value whose fields take precedence according to each field's strategy.
+    ///
+    /// # Returns
+    /// - `Self` - The merged value.
+    fn merged(mut self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        self.merge(other);
+        self
+    }
+}
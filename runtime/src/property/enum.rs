@@ -0,0 +1,19 @@
+/// Represents an error raised while building a struct from a string-keyed property map.
+///
+/// # Variants
+/// - `MissingProperty` - A required property is absent from the map.
+/// - `InvalidValue` - A property value could not be parsed into the field's type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyError {
+    /// A required property is absent from the map.
+    MissingProperty(&'static str),
+    /// A property value could not be parsed into the field's type.
+    InvalidValue {
+        /// The key of the property.
+        key: &'static str,
+        /// The value that failed to parse.
+        value: String,
+        /// The message of the parse error.
+        message: String,
+    },
+}
@@ -0,0 +1,25 @@
+use crate::*;
+
+/// Implementation of Display trait for PropertyError enum.
+impl Display for PropertyError {
+    /// Formats the `PropertyError` into a human readable message.
+    ///
+    /// # Arguments
+    /// - `self` - The reference to the `PropertyError` instance being formatted.
+    /// - `Formatter<'_>` - The formatter to write the message to.
+    ///
+    /// # Returns
+    /// - `Result` - indicating success or failure of the formatting operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyError::MissingProperty(key) => write!(f, "Missing property: {key}"),
+            PropertyError::InvalidValue {
+                key,
+                value,
+                message,
+            } => write!(f, "Invalid value '{value}' for property '{key}': {message}"),
+        }
+    }
+}
+
+impl Error for PropertyError {}
@@ -0,0 +1,4 @@
+mod r#enum;
+mod r#impl;
+
+pub use r#enum::*;
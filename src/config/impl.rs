@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Implements parsing of a single `name: Type = expr` computed getter declaration.
+impl Parse for ComputedGetter {
+    /// Parses a `ComputedGetter` from the given parse stream.
+    ///
+    /// The expression extends up to the next top-level comma, so several computed
+    /// getters can be declared in one `computed(...)` list.
+    ///
+    /// # Arguments
+    /// - `ParseStream` - The stream to parse from.
+    ///
+    /// # Returns
+    /// - `syn::Result<ComputedGetter>` - The parsed computed getter or a parse error.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty: Type = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let mut expr: TokenStream2 = TokenStream2::new();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            let token: TokenTree2 = input.parse()?;
+            expr.extend(std::iter::once(token));
+        }
+        Ok(ComputedGetter { name, ty, expr })
+    }
+}
+
+/// Implements parsing of a single `name: Type` flattened constructor parameter.
+impl Parse for FlattenParam {
+    /// Parses a `FlattenParam` from the given parse stream.
+    ///
+    /// # Arguments
+    /// - `ParseStream` - The stream to parse from.
+    ///
+    /// # Returns
+    /// - `syn::Result<FlattenParam>` - The parsed parameter or a parse error.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty: Type = input.parse()?;
+        Ok(FlattenParam { name, ty })
+    }
+}
@@ -1,3 +1,4 @@
+mod r#impl;
 mod r#struct;
 
 pub(crate) use r#struct::*;
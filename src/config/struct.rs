@@ -12,6 +12,10 @@ use std::collections::HashSet;
 /// - `visibility` - A `Visibility` that defines the visibility of the function.
 /// - `return_type` - A `ReturnType` that specifies the return type behavior for getters.
 /// - `param_type_override` - Optional custom parameter type to use instead of deriving from field type.
+/// - `lazy_init` - Optional initializer path for lazy getters on `OnceCell`/`OnceLock` fields.
+/// - `atomic_ordering` - Optional memory ordering for atomic accessors, empty when `atomic` is given without one.
+/// - `fetch_add` - Whether atomic setters also generate `fetch_add_` and `increment_` helpers.
+/// - `unwrap` - Whether accessors of `Cell`, `RefCell`, `Mutex` and `RwLock` fields operate on the inner value.
 /// - `skip_flags` - A set of `SkipFlag` indicating which function types should be skipped.
 /// - `added_flags` - A set of `AddedFlag` indicating which function types have been added.
 #[derive(Clone, Debug, Default)]
@@ -24,8 +28,58 @@ pub(crate) struct Config {
     pub(crate) return_type: ReturnType,
     /// Optional custom parameter type to use instead of deriving from field type.
     pub(crate) param_type_override: Option<TokenStream2>,
+    /// Optional initializer path for lazy getters on `OnceCell`/`OnceLock` fields.
+    pub(crate) lazy_init: Option<TokenStream2>,
+    /// Optional memory ordering for atomic accessors, empty when `atomic` is given without one.
+    pub(crate) atomic_ordering: Option<TokenStream2>,
+    /// Whether atomic setters also generate `fetch_add_` and `increment_` helpers.
+    pub(crate) fetch_add: bool,
+    /// Whether accessors of `Cell`, `RefCell`, `Mutex` and `RwLock` fields operate on the inner value.
+    pub(crate) unwrap: bool,
     /// A set of `FuncType` indicating which function types should be skipped.
     pub(crate) skip_flags: HashSet<FuncType>,
     /// A set of `FuncType` indicating which function types have been added.
     pub(crate) added_flags: HashSet<FuncType>,
 }
+
+/// Represents a computed getter declared on a struct with `#[get(computed(name: Type = expr))]`.
+///
+/// # Fields
+/// - `name` - The name of the computed value, used to build the getter name.
+/// - `ty` - The return type of the getter.
+/// - `expr` - The expression evaluated by the getter, with `self` in scope.
+#[derive(Clone)]
+pub(crate) struct ComputedGetter {
+    /// The name of the computed value, used to build the getter name.
+    pub(crate) name: Ident,
+    /// The return type of the getter.
+    pub(crate) ty: Type,
+    /// The expression evaluated by the getter, with `self` in scope.
+    pub(crate) expr: TokenStream2,
+}
+
+/// Represents a constructor parameter of a flattened field declared with `#[new(flatten(name: Type))]`.
+///
+/// # Fields
+/// - `name` - The name of the parameter in the outer constructor.
+/// - `ty` - The type of the parameter.
+#[derive(Clone)]
+pub(crate) struct FlattenParam {
+    /// The name of the parameter in the outer constructor.
+    pub(crate) name: Ident,
+    /// The type of the parameter.
+    pub(crate) ty: Type,
+}
+
+/// Represents the container-level orderings declared with `#[get(atomic(..))]` and `#[set(atomic(..))]`.
+///
+/// # Fields
+/// - `load` - The ordering used by getters of atomic fields without their own ordering.
+/// - `store` - The ordering used by setters of atomic fields without their own ordering.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ContainerAtomicOrdering {
+    /// The ordering used by getters of atomic fields without their own ordering.
+    pub(crate) load: Option<TokenStream2>,
+    /// The ordering used by setters of atomic fields without their own ordering.
+    pub(crate) store: Option<TokenStream2>,
+}
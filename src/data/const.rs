@@ -0,0 +1,12 @@
+/// Error message indicating that an unknown option was passed to the #[data(...)] container attribute.
+pub(crate) const UNKNOWN_DATA_OPTION: &str =
+    "Unknown #[data(...)] option, expected one of `new`, `debug`, `display`, `eq`, `hash`:";
+
+/// Name of the generated function checking that `#[new(...)]` attributes have a constructor to configure.
+pub(crate) const DATA_NEW_ATTRIBUTE_CHECK: &str = "__lombok_check_new_attributes";
+
+/// Name of the generated function checking that `#[debug(...)]` attributes have a `Debug` implementation to configure.
+pub(crate) const DATA_DEBUG_ATTRIBUTE_CHECK: &str = "__lombok_check_debug_attributes";
+
+/// Name of the generated function checking that `#[default(...)]` attributes have a `Default` implementation to configure.
+pub(crate) const DATA_DEFAULT_ATTRIBUTE_CHECK: &str = "__lombok_check_default_attributes";
@@ -0,0 +1,136 @@
+use crate::*;
+
+/// Generates an associated function whose body only compiles if the attributes it checks
+/// are consumed by an implementation of the struct.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+/// - `Option<TypeParamBound>` - The bound added to every type parameter, matching the checked implementation.
+/// - `&Ident` - The name of the generated function.
+/// - `Vec<TokenStream2>` - The checks, each spanned to the attribute it belongs to.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated function, or nothing without checks.
+fn build_attribute_check(
+    input: &DeriveInput,
+    bound: Option<TypeParamBound>,
+    check_name: &Ident,
+    checks: Vec<TokenStream2>,
+) -> TokenStream2 {
+    if checks.is_empty() {
+        return quote! {};
+    }
+    let name: &Ident = &input.ident;
+    let generics: Generics = match bound {
+        Some(bound) => add_type_param_bound(&input.generics, bound),
+        None => input.generics.clone(),
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(dead_code)]
+            fn #check_name() {
+                #(#checks)*
+            }
+        }
+    }
+}
+
+/// Generates compile-time checks rejecting `#[new(...)]`, `#[debug(...)]` and `#[default(...)]`
+/// attributes that no enabled option or companion derive consumes.
+///
+/// A derive cannot see the other derives of its struct, so instead of guessing, the checks
+/// require the implementation the attribute configures: `new` for `#[new(...)]`, `Debug` for
+/// `#[debug(...)]` and, on fields without `#[new(...)]`, `Default` for `#[default(...)]`.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+/// - `&[String]` - The options of the `#[data(...)]` attribute.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated checks.
+fn build_companion_attribute_checks(input: &DeriveInput, options: &[String]) -> TokenStream2 {
+    let has_option = |option: &str| options.iter().any(|enabled| enabled == option);
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        _ => Vec::new(),
+    };
+    let mut new_checks: Vec<TokenStream2> = Vec::new();
+    let mut debug_checks: Vec<TokenStream2> = Vec::new();
+    let mut default_checks: Vec<TokenStream2> = Vec::new();
+    let field_attrs = fields.iter().map(|field| (&field.attrs, true));
+    for (attrs, is_field) in std::iter::once((&input.attrs, false)).chain(field_attrs) {
+        let has_new_attr: bool = attrs.iter().any(|attr| attr.path().is_ident(NEW));
+        for attr in attrs {
+            let path: &Path = attr.path();
+            if path.is_ident(NEW) && !has_option(NEW) {
+                new_checks.push(quote_spanned! { path.span() => let _ = Self::new; });
+            } else if path.is_ident(DEBUG) && !has_option(DEBUG) {
+                debug_checks.push(quote_spanned! { path.span() =>
+                    let _ = <Self as std::fmt::Debug>::fmt;
+                });
+            } else if path.is_ident(DEFAULT) && is_field && !has_new_attr && !has_option(NEW) {
+                default_checks.push(quote_spanned! { path.span() =>
+                    let _ = <Self as std::default::Default>::default;
+                });
+            }
+        }
+    }
+    let mut checks: TokenStream2 = TokenStream2::new();
+    checks.extend(build_attribute_check(
+        input,
+        None,
+        &format_ident!("{}", DATA_NEW_ATTRIBUTE_CHECK),
+        new_checks,
+    ));
+    checks.extend(build_attribute_check(
+        input,
+        Some(parse_quote!(std::fmt::Debug)),
+        &format_ident!("{}", DATA_DEBUG_ATTRIBUTE_CHECK),
+        debug_checks,
+    ));
+    checks.extend(build_attribute_check(
+        input,
+        Some(parse_quote!(std::default::Default)),
+        &format_ident!("{}", DATA_DEFAULT_ATTRIBUTE_CHECK),
+        default_checks,
+    ));
+    checks
+}
+
+/// Generates the additional implementations requested by the `#[data(...)]` container attribute.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated constructor, `Debug`, `Display`, equality and hashing implementations.
+pub(crate) fn inner_data_options(input: TokenStream) -> TokenStream {
+    let source: TokenStream = input.clone();
+    let derive_input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let options: Vec<String> = parse_attribute_flags(&derive_input.attrs, DATA);
+    let mut result: TokenStream2 = build_companion_attribute_checks(&derive_input, &options);
+    for option in options {
+        match option.as_str() {
+            NEW => {
+                let visibility: Visibility = parse_new_visibility(&derive_input);
+                result.extend(TokenStream2::from(inner_new_constructor(
+                    &derive_input,
+                    visibility,
+                )));
+            }
+            DEBUG => result.extend(TokenStream2::from(inner_custom_debug(source.clone()))),
+            DISPLAY => result.extend(TokenStream2::from(inner_display_debug(source.clone()))),
+            EQ => result.extend(build_eq_impl(&derive_input)),
+            HASH => result.extend(build_hash_impl(&derive_input)),
+            _ => panic!("{} `{}`", UNKNOWN_DATA_OPTION, option),
+        }
+    }
+    result.into()
+}
@@ -0,0 +1,36 @@
+/// Error message indicating that #[derive(Delegate)] is only supported for structs.
+pub(crate) const UNSUPPORTED_DELEGATE_DERIVE: &str =
+    "#[derive(Delegate)] is only supported for structs.";
+
+/// Error message indicating that a delegated method list could not be parsed.
+pub(crate) const INVALID_DELEGATE_METHODS: &str = "Invalid #[delegate(methods(...))] list, expected method names or `fn name(&self, arg: Type) -> Type` signatures";
+
+/// Error message indicating that the `trait = ...` value of a delegate attribute is not a path.
+pub(crate) const INVALID_DELEGATE_TRAIT: &str = "Invalid #[delegate(trait = ...)] value, expected a trait path such as `Read` or `Conv<u8, u16>`";
+
+/// Error message indicating that the signature of a delegated method cannot be inferred.
+pub(crate) const UNKNOWN_DELEGATE_METHOD: &str =
+    "Cannot infer the signature of the delegated method, declare it as `fn name(&self) -> Type`";
+
+/// Error message indicating that a delegated trait lists a method without its signature.
+pub(crate) const DELEGATE_TRAIT_REQUIRES_SIGNATURES: &str =
+    "#[delegate(trait = ...)] requires full `fn` signatures in `methods(...)`";
+
+/// Error message indicating that a delegate attribute names neither methods nor a trait.
+pub(crate) const EMPTY_DELEGATE: &str =
+    "#[delegate(...)] requires a `methods(...)` list, optionally with `trait = Trait`";
+
+/// The delegated `len` method, inferred as `fn len(&self) -> usize`.
+pub(crate) const DELEGATE_LEN: &str = "len";
+
+/// The delegated `is_empty` method, inferred as `fn is_empty(&self) -> bool`.
+pub(crate) const DELEGATE_IS_EMPTY: &str = "is_empty";
+
+/// The delegated `iter` method, inferred from the `IntoIterator` implementation of `&T`.
+pub(crate) const DELEGATE_ITER: &str = "iter";
+
+/// The delegated `iter_mut` method, inferred from the `IntoIterator` implementation of `&mut T`.
+pub(crate) const DELEGATE_ITER_MUT: &str = "iter_mut";
+
+/// The delegated `clear` method, inferred as `fn clear(&mut self)`.
+pub(crate) const DELEGATE_CLEAR: &str = "clear";
@@ -0,0 +1,224 @@
+use crate::*;
+
+/// Infers the signature of a delegated method listed by name only.
+///
+/// Only the common collection methods whose signatures do not depend on the
+/// element type are supported; every other method must declare its signature.
+///
+/// # Arguments
+///
+/// - `&Ident` - The name of the delegated method.
+/// - `&Type` - The type of the field the method is forwarded to.
+///
+/// # Returns
+///
+/// - `DelegateSignature` - The inferred signature.
+fn infer_delegate_signature(method_name: &Ident, field_ty: &Type) -> DelegateSignature {
+    let (receiver_mut, output): (bool, Option<Type>) = match method_name.to_string().as_str() {
+        DELEGATE_LEN => (false, Some(parse_quote!(usize))),
+        DELEGATE_IS_EMPTY => (false, Some(parse_quote!(bool))),
+        DELEGATE_ITER => (
+            false,
+            Some(parse_quote!(<&'_ #field_ty as std::iter::IntoIterator>::IntoIter)),
+        ),
+        DELEGATE_ITER_MUT => (
+            true,
+            Some(parse_quote!(<&'_ mut #field_ty as std::iter::IntoIterator>::IntoIter)),
+        ),
+        DELEGATE_CLEAR => (true, None),
+        _ => panic!("{} `{}`.", UNKNOWN_DELEGATE_METHOD, method_name),
+    };
+    DelegateSignature {
+        receiver_mut,
+        args: Vec::new(),
+        output,
+    }
+}
+
+/// Generates a method forwarding to a field, either inherent or inside a trait implementation.
+///
+/// # Arguments
+///
+/// - `Option<&TokenStream2>` - The visibility of an inherent method, or `None` inside a trait implementation.
+/// - `&Member` - The field name or tuple index to forward to.
+/// - `&Type` - The type of the field.
+/// - `&DelegateMethod` - The delegated method.
+/// - `Option<&TokenStream2>` - The delegated trait, used to call the method unambiguously.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated forwarding method.
+fn build_delegate_method(
+    vis: Option<&TokenStream2>,
+    member: &Member,
+    field_ty: &Type,
+    method: &DelegateMethod,
+    trait_path: Option<&TokenStream2>,
+) -> TokenStream2 {
+    let name: &Ident = &method.name;
+    let signature: DelegateSignature = match &method.signature {
+        Some(signature) => signature.clone(),
+        None if trait_path.is_some() => panic!("{}", DELEGATE_TRAIT_REQUIRES_SIGNATURES),
+        None => infer_delegate_signature(name, field_ty),
+    };
+    let receiver: TokenStream2 = if signature.receiver_mut {
+        quote! { &mut self }
+    } else {
+        quote! { &self }
+    };
+    let field_ref: TokenStream2 = if signature.receiver_mut {
+        quote! { &mut self.#member }
+    } else {
+        quote! { &self.#member }
+    };
+    let arg_names: Vec<&Ident> = signature
+        .args
+        .iter()
+        .map(|(arg_name, _)| arg_name)
+        .collect();
+    let arg_types: Vec<&Type> = signature.args.iter().map(|(_, arg_ty)| arg_ty).collect();
+    let output: TokenStream2 = match &signature.output {
+        Some(output_ty) => quote! { -> #output_ty },
+        None => quote! {},
+    };
+    let call: TokenStream2 = match trait_path {
+        Some(trait_path) => quote! {
+            <#field_ty as #trait_path>::#name(#field_ref, #(#arg_names),*)
+        },
+        None => quote! {
+            self.#member.#name(#(#arg_names),*)
+        },
+    };
+    quote! {
+        #[inline(always)]
+        #vis fn #name(#receiver, #(#arg_names: #arg_types),*) #output {
+            #call
+        }
+    }
+}
+
+/// Splits a `#[delegate(...)]` attribute into its method list, trait path and remaining options.
+///
+/// # Arguments
+///
+/// - `&Attribute` - The delegate attribute.
+///
+/// # Returns
+///
+/// - `(Vec<DelegateMethod>, Option<TokenStream2>, TokenStream2)` - The methods, the trait path and the option tokens.
+fn parse_delegate_attribute(
+    attr: &Attribute,
+) -> (Vec<DelegateMethod>, Option<TokenStream2>, TokenStream2) {
+    let mut methods: Vec<DelegateMethod> = Vec::new();
+    let mut trait_path: Option<TokenStream2> = None;
+    let mut option_tokens: TokenStream2 = TokenStream2::new();
+    let Ok(meta_list) = attr.meta.require_list() else {
+        panic!("{}", EMPTY_DELEGATE);
+    };
+    let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+    while let Some(token) = tokens_iter.next() {
+        if let TokenTree2::Ident(ident) = &token
+            && ident == METHODS
+            && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+            && group.delimiter() == Delimiter::Parenthesis
+        {
+            let method_list: Punctuated<DelegateMethod, Token![,]> =
+                Punctuated::<DelegateMethod, Token![,]>::parse_terminated
+                    .parse2(group.stream())
+                    .unwrap_or_else(|error| panic!("{}: {}", INVALID_DELEGATE_METHODS, error));
+            methods.extend(method_list);
+            tokens_iter.next();
+            continue;
+        }
+        if let TokenTree2::Ident(ident) = &token
+            && ident == TRAIT
+            && let Some(TokenTree2::Punct(punct)) = tokens_iter.peek()
+            && punct.as_char() == EQUAL
+        {
+            tokens_iter.next();
+            let remaining: TokenStream2 = tokens_iter.by_ref().collect();
+            let (path, rest): (Path, TokenStream2) =
+                (|input: ParseStream| Ok((input.parse::<Path>()?, input.parse::<TokenStream2>()?)))
+                    .parse2(remaining)
+                    .unwrap_or_else(|error| panic!("{}: {}", INVALID_DELEGATE_TRAIT, error));
+            trait_path = Some(path.to_token_stream());
+            tokens_iter = rest.into_iter().peekable();
+            continue;
+        }
+        option_tokens.extend(std::iter::once(token));
+    }
+    if methods.is_empty() {
+        panic!("{}", EMPTY_DELEGATE);
+    }
+    (methods, trait_path, option_tokens)
+}
+
+/// Generates forwarding methods and trait implementations for fields marked with `#[delegate(...)]`.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated inherent methods and trait implementations.
+pub(crate) fn inner_delegate(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Data::Struct(data_struct) = &input.data else {
+        panic!("{}", UNSUPPORTED_DELEGATE_DERIVE);
+    };
+    let mut inherent_methods: Vec<TokenStream2> = Vec::new();
+    let mut trait_impls: Vec<TokenStream2> = Vec::new();
+    for (index, field) in data_struct.fields.iter().enumerate() {
+        let member: Member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(DELEGATE))
+        {
+            let (methods, trait_path, option_tokens) = parse_delegate_attribute(attr);
+            match trait_path {
+                Some(trait_path) => {
+                    let forwarded: Vec<TokenStream2> = methods
+                        .iter()
+                        .map(|method| {
+                            build_delegate_method(
+                                None,
+                                &member,
+                                &field.ty,
+                                method,
+                                Some(&trait_path),
+                            )
+                        })
+                        .collect();
+                    trait_impls.push(quote! {
+                        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+                            #(#forwarded)*
+                        }
+                    });
+                }
+                None => {
+                    let vis: TokenStream2 = analyze_attributes(option_tokens)
+                        .visibility
+                        .to_token_stream();
+                    inherent_methods.extend(methods.iter().map(|method| {
+                        build_delegate_method(Some(&vis), &member, &field.ty, method, None)
+                    }));
+                }
+            }
+        }
+    }
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#inherent_methods)*
+        }
+
+        #(#trait_impls)*
+    };
+    expanded.into()
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+/// Implements parsing of a bare method name or a `fn` signature listed in `#[delegate(methods(...))]`.
+impl Parse for DelegateMethod {
+    /// Parses a `DelegateMethod` from the given parse stream.
+    ///
+    /// # Arguments
+    /// - `ParseStream` - The stream to parse from.
+    ///
+    /// # Returns
+    /// - `syn::Result<DelegateMethod>` - The parsed method or a parse error.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !input.peek(Token![fn]) {
+            return Ok(DelegateMethod {
+                name: input.parse()?,
+                signature: None,
+            });
+        }
+        input.parse::<Token![fn]>()?;
+        let name: Ident = input.parse()?;
+        let content: ParseBuffer;
+        parenthesized!(content in input);
+        content.parse::<Token![&]>()?;
+        let receiver_mut: bool = content.parse::<Option<Token![mut]>>()?.is_some();
+        content.parse::<Token![self]>()?;
+        let mut args: Vec<(Ident, Type)> = Vec::new();
+        while !content.is_empty() {
+            content.parse::<Token![,]>()?;
+            if content.is_empty() {
+                break;
+            }
+            let arg_name: Ident = content.parse()?;
+            content.parse::<Token![:]>()?;
+            let arg_ty: Type = content.parse()?;
+            args.push((arg_name, arg_ty));
+        }
+        let output: Option<Type> = if input.peek(Token![->]) {
+            input.parse::<Token![->]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(DelegateMethod {
+            name,
+            signature: Some(DelegateSignature {
+                receiver_mut,
+                args,
+                output,
+            }),
+        })
+    }
+}
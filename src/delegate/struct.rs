@@ -0,0 +1,30 @@
+use crate::*;
+
+/// Represents a method listed in `#[delegate(methods(...))]`.
+///
+/// # Fields
+/// - `name` - The name of the forwarded method.
+/// - `signature` - The declared receiver, arguments and return type, or `None` for a bare method name.
+#[derive(Clone)]
+pub(crate) struct DelegateMethod {
+    /// The name of the forwarded method.
+    pub(crate) name: Ident,
+    /// The declared receiver, arguments and return type, or `None` for a bare method name.
+    pub(crate) signature: Option<DelegateSignature>,
+}
+
+/// Represents the signature declared for a delegated method, such as `fn read(&mut self, buf: &mut [u8]) -> usize`.
+///
+/// # Fields
+/// - `receiver_mut` - Whether the method takes `&mut self` instead of `&self`.
+/// - `args` - The argument names and types after the receiver.
+/// - `output` - The return type, or `None` for methods returning `()`.
+#[derive(Clone)]
+pub(crate) struct DelegateSignature {
+    /// Whether the method takes `&mut self` instead of `&self`.
+    pub(crate) receiver_mut: bool,
+    /// The argument names and types after the receiver.
+    pub(crate) args: Vec<(Ident, Type)>,
+    /// The return type, or `None` for methods returning `()`.
+    pub(crate) output: Option<Type>,
+}
@@ -0,0 +1,2 @@
+/// Error message indicating that #[derive(Diff)] is only supported for structs.
+pub(crate) const UNSUPPORTED_DIFF_DERIVE: &str = "#[derive(Diff)] is only supported for structs.";
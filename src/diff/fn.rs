@@ -0,0 +1,83 @@
+use crate::*;
+
+/// Generates the comparison for a single struct field.
+///
+/// Fields marked `#[debug(skip)]` are still compared, but their values are
+/// redacted so they never appear in the generated change set.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to compare.
+/// - `usize` - The index of the field, used as its name for tuple fields.
+///
+/// # Returns
+///
+/// - `Option<TokenStream2>` - The generated comparison, or `None` if the field is skipped.
+fn build_field_diff(field: &Field, index: usize) -> Option<TokenStream2> {
+    if should_skip_field(field, FuncType::Diff) {
+        return None;
+    }
+    let (member, field_name_str): (Member, String) = match &field.ident {
+        Some(ident) => (
+            Member::Named(ident.clone()),
+            get_clean_attr_name(&ident.to_string()),
+        ),
+        None => (Member::Unnamed(Index::from(index)), index.to_string()),
+    };
+    let change: TokenStream2 = if should_skip_field(field, FuncType::Debug) {
+        quote! {
+            lombok_macros_runtime::FieldChange::redacted(#field_name_str)
+        }
+    } else {
+        quote! {
+            lombok_macros_runtime::FieldChange::new(
+                #field_name_str,
+                format!("{:?}", self.#member),
+                format!("{:?}", other.#member),
+            )
+        }
+    };
+    Some(quote! {
+        if self.#member != other.#member {
+            changes.push(#change);
+        }
+    })
+}
+
+/// Generates a `diff` method listing the fields that differ between two instances.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `diff` implementation.
+pub(crate) fn inner_diff(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let generics: Generics = add_type_param_bound(&input.generics, parse_quote!(std::fmt::Debug));
+    let generics: Generics = add_type_param_bound(&generics, parse_quote!(std::cmp::PartialEq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let comparisons: Vec<TokenStream2> = match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| build_field_diff(field, index))
+            .collect(),
+        _ => panic!("{}", UNSUPPORTED_DIFF_DERIVE),
+    };
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            #vis fn diff(&self, other: &Self) -> Vec<lombok_macros_runtime::FieldChange> {
+                let mut changes: Vec<lombok_macros_runtime::FieldChange> = Vec::new();
+                #(#comparisons)*
+                changes
+            }
+        }
+    };
+    expanded.into()
+}
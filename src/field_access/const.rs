@@ -0,0 +1,3 @@
+/// Error message indicating that #[derive(FieldAccess)] is only supported for structs.
+pub(crate) const UNSUPPORTED_FIELD_ACCESS_DERIVE: &str =
+    "#[derive(FieldAccess)] is only supported for structs.";
@@ -0,0 +1,95 @@
+use crate::*;
+
+/// Collects the member, name and type of every field exposed through `FieldAccess`.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+///
+/// # Returns
+///
+/// - `Vec<(Member, String, Type)>` - The accessible fields in declaration order.
+fn collect_accessible_fields(input: &DeriveInput) -> Vec<(Member, String, Type)> {
+    match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !should_skip_field(field, FuncType::FieldAccess))
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => (
+                    Member::Named(ident.clone()),
+                    get_clean_attr_name(&ident.to_string()),
+                    field.ty.clone(),
+                ),
+                None => (
+                    Member::Unnamed(Index::from(index)),
+                    index.to_string(),
+                    field.ty.clone(),
+                ),
+            })
+            .collect(),
+        _ => panic!("{}", UNSUPPORTED_FIELD_ACCESS_DERIVE),
+    }
+}
+
+/// Implements the runtime `FieldAccess` trait for a struct.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `FieldAccess` implementation.
+pub(crate) fn inner_field_access(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let generics: Generics = add_type_param_bound(&input.generics, parse_quote!('static));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let fields: Vec<(Member, String, Type)> = collect_accessible_fields(&input);
+    let members: Vec<&Member> = fields.iter().map(|(member, _, _)| member).collect();
+    let name_strs: Vec<&String> = fields.iter().map(|(_, name_str, _)| name_str).collect();
+    let types: Vec<&Type> = fields.iter().map(|(_, _, ty)| ty).collect();
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics lombok_macros_runtime::FieldAccess for #name #ty_generics #where_clause {
+            fn get_field(&self, name: &str) -> Option<&dyn std::any::Any> {
+                match name {
+                    #(#name_strs => Some(&self.#members),)*
+                    _ => None,
+                }
+            }
+
+            fn get_field_mut(&mut self, name: &str) -> Option<&mut dyn std::any::Any> {
+                match name {
+                    #(#name_strs => Some(&mut self.#members),)*
+                    _ => None,
+                }
+            }
+
+            fn set_field(
+                &mut self,
+                name: &str,
+                value: Box<dyn std::any::Any>,
+            ) -> Result<(), lombok_macros_runtime::FieldError> {
+                match name {
+                    #(#name_strs => {
+                        self.#members = *value.downcast::<#types>().map_err(|_| {
+                            lombok_macros_runtime::FieldError::TypeMismatch {
+                                field: name.to_string(),
+                                expected: std::any::type_name::<#types>(),
+                            }
+                        })?;
+                        Ok(())
+                    })*
+                    _ => Err(lombok_macros_runtime::FieldError::UnknownField(name.to_string())),
+                }
+            }
+
+            fn field_names(&self) -> &'static [&'static str] {
+                &[#(#name_strs),*]
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,17 @@
+/// Error message indicating that #[derive(FieldNames)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_FIELD_NAMES_DERIVE: &str =
+    "#[derive(FieldNames)] is only supported for structs with named fields.";
+
+/// Prefix for generated field name constants.
+pub(crate) const FIELD_CONST_PREFIX: &str = "FIELD_";
+
+/// Suffix for the generated field enum name.
+pub(crate) const FIELD_ENUM_SUFFIX: &str = "Field";
+
+/// Error message indicating that a field name does not produce a valid field enum variant.
+pub(crate) const INVALID_FIELD_VARIANT: &str =
+    "#[derive(FieldNames)] cannot build a field enum variant from this field name";
+
+/// Error message indicating that two fields produce the same field enum variant.
+pub(crate) const DUPLICATE_FIELD_VARIANT: &str =
+    "#[derive(FieldNames)] fields map to the same field enum variant";
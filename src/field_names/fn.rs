@@ -0,0 +1,170 @@
+use crate::*;
+
+/// Checks if a field should be skipped for field name generation.
+///
+/// # Arguments
+///
+/// - `&Field` - The field structure to analyze for skip attribute.
+///
+/// # Returns
+///
+/// - `bool` - True if the field should be skipped, false otherwise.
+pub(crate) fn should_skip_field_for_field_name(field: &Field) -> bool {
+    should_skip_field(field, FuncType::FieldName)
+}
+
+/// Collects the cleaned names and exposed names of all non-skipped named fields.
+///
+/// The exposed name is the cleaned name, or the `#[serde(rename = "...")]` wire name
+/// when the struct is marked `#[lombok(serde_names)]`.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+///
+/// # Returns
+///
+/// - `Vec<(Ident, String, String)>` - The field identifiers with their names without the raw identifier prefix and their exposed names.
+fn collect_field_names(input: &DeriveInput) -> Vec<(Ident, String, String)> {
+    let serde_names: bool = has_serde_names(&input.attrs);
+    let rename_all: Option<String> = serde_names
+        .then(|| parse_serde_rename_all(&input.attrs))
+        .flatten();
+    match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields
+                .named
+                .iter()
+                .filter(|field| !should_skip_field_for_field_name(field))
+                .filter_map(|field| {
+                    let field_name: &Ident = field.ident.as_ref()?;
+                    let clean_name: String = get_clean_attr_name(&field_name.to_string());
+                    let exposed_name: String = serde_names
+                        .then(|| {
+                            resolve_serde_name(
+                                &field.attrs,
+                                &clean_name,
+                                rename_all.as_deref(),
+                                false,
+                            )
+                        })
+                        .flatten()
+                        .unwrap_or_else(|| clean_name.clone());
+                    Some((field_name.clone(), clean_name, exposed_name))
+                })
+                .collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => panic!("{}", UNSUPPORTED_FIELD_NAMES_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_FIELD_NAMES_DERIVE),
+    }
+}
+
+/// Builds the field enum variant names, rejecting names that are not valid identifiers
+/// or that more than one field maps to.
+///
+/// # Arguments
+///
+/// - `&[(Ident, String, String)]` - The collected field identifiers, clean names and exposed names.
+///
+/// # Returns
+///
+/// - `syn::Result<Vec<Ident>>` - The variant names, or a spanned error naming the offending fields.
+fn build_field_variants(field_names: &[(Ident, String, String)]) -> syn::Result<Vec<Ident>> {
+    let mut variant_names: Vec<Ident> = Vec::with_capacity(field_names.len());
+    for (field_name, clean_name, _) in field_names {
+        let variant_str: String = to_pascal_case(clean_name);
+        let variant_name: Ident = syn::parse_str::<Ident>(&variant_str).map_err(|_| {
+            syn::Error::new_spanned(
+                field_name,
+                format!(
+                    "{}: `{}` becomes `{}`",
+                    INVALID_FIELD_VARIANT, field_name, variant_str
+                ),
+            )
+        })?;
+        if let Some(index) = variant_names.iter().position(|name| *name == variant_name) {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                format!(
+                    "{}: `{}` and `{}` both become `{}`",
+                    DUPLICATE_FIELD_VARIANT, field_names[index].0, field_name, variant_str
+                ),
+            ));
+        }
+        variant_names.push(variant_name);
+    }
+    Ok(variant_names)
+}
+
+/// Generates field name constants, a field enum and a `field_names` function for a struct.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated constants, enum and implementations.
+pub(crate) fn inner_field_names(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let enum_name: Ident = format_ident!("{}{}", name, FIELD_ENUM_SUFFIX);
+    let field_names: Vec<(Ident, String, String)> = collect_field_names(&input);
+    let variant_names: Vec<Ident> = match build_field_variants(&field_names) {
+        Ok(variant_names) => variant_names,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let name_strs: Vec<&String> = field_names
+        .iter()
+        .map(|(_, _, name_str)| name_str)
+        .collect();
+    let const_names: Vec<Ident> = field_names
+        .iter()
+        .map(|(_, clean_name, _)| {
+            format_ident!("{}{}", FIELD_CONST_PREFIX, clean_name.to_uppercase())
+        })
+        .collect();
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(
+                #vis const #const_names: &'static str = #name_strs;
+            )*
+
+            #[inline(always)]
+            #vis fn field_names() -> &'static [&'static str] {
+                &[#(#name_strs),*]
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #vis enum #enum_name {
+            #(#variant_names),*
+        }
+
+        impl #enum_name {
+            #vis const ALL: &'static [#enum_name] = &[#(#enum_name::#variant_names),*];
+
+            #[inline(always)]
+            #vis fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#enum_name::#variant_names => #name_strs,)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for #enum_name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#name_strs => Ok(#enum_name::#variant_names),)*
+                    _ => Err(format!("Unknown field name: {s}")),
+                }
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,7 @@
+/// Error message indicating that #[derive(FromEnv)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_FROM_ENV_DERIVE: &str =
+    "#[derive(FromEnv)] is only supported for structs with named fields.";
+
+/// Error message indicating that an #[env(default = ...)] value is not a literal.
+pub(crate) const INVALID_ENV_DEFAULT: &str =
+    "#[env(default = ...)] expects a literal, such as `default = \"8080\"` or `default = 8080`";
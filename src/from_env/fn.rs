@@ -0,0 +1,160 @@
+use crate::*;
+
+/// Parses the `#[env(default = ...)]` value of a field into the string parsed when the variable is unset.
+///
+/// String literals are used as is; integer, float, boolean and character literals,
+/// optionally negated, are converted to their textual form.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to read the default of.
+///
+/// # Returns
+///
+/// - `syn::Result<Option<String>>` - The default text, `None` without a default,
+///   or an error spanning a value that is not a literal.
+fn parse_env_default(field: &Field) -> syn::Result<Option<String>> {
+    let Some(value) = parse_attribute_value(&field.attrs, ENV, DEFAULT) else {
+        return Ok(None);
+    };
+    let invalid_default = || syn::Error::new_spanned(&value, INVALID_ENV_DEFAULT);
+    let (negative, lit): (bool, Lit) = match parse2::<Expr>(value.clone()) {
+        Ok(Expr::Lit(expr_lit)) => (false, expr_lit.lit),
+        Ok(Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        })) => match *expr {
+            Expr::Lit(expr_lit) => (true, expr_lit.lit),
+            _ => return Err(invalid_default()),
+        },
+        _ => return Err(invalid_default()),
+    };
+    let sign: &str = if negative { "-" } else { "" };
+    match lit {
+        Lit::Str(lit) if !negative => Ok(Some(lit.value())),
+        Lit::Int(lit) => Ok(Some(format!("{sign}{}", lit.base10_digits()))),
+        Lit::Float(lit) => Ok(Some(format!("{sign}{}", lit.base10_digits()))),
+        Lit::Bool(lit) if !negative => Ok(Some(lit.value.to_string())),
+        Lit::Char(lit) if !negative => Ok(Some(lit.value().to_string())),
+        _ => Err(invalid_default()),
+    }
+}
+
+/// Generates the read of a single field and reports whether the field is required.
+///
+/// # Arguments
+///
+/// - `&Field` - The named field to read.
+/// - `&Ident` - The local the value is bound to.
+/// - `&str` - The prefix prepended to every variable name.
+///
+/// # Returns
+///
+/// - `syn::Result<(TokenStream2, bool)>` - The `let` binding reading the field and whether it is required.
+fn build_field_env_read(
+    field: &Field,
+    binding: &Ident,
+    prefix: &str,
+) -> syn::Result<(TokenStream2, bool)> {
+    let field_name: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+    let var_name: String = format!(
+        "{prefix}{}",
+        parse_attribute_string(&field.attrs, ENV, NAME)?
+            .unwrap_or_else(|| get_clean_attr_name(&field_name.to_string()).to_uppercase())
+    );
+    let default: TokenStream2 = match parse_env_default(field)? {
+        Some(default) => quote! { Some(#default) },
+        None => quote! { None },
+    };
+    Ok(match extract_option_inner_type(&field.ty) {
+        Some(inner_ty) => (
+            quote! {
+                let #binding: Option<#inner_ty> = lombok_macros_runtime::parse_optional_env_var(
+                    #var_name,
+                    #default,
+                    &mut __lombok_errors,
+                );
+            },
+            false,
+        ),
+        None => {
+            let field_ty: &Type = &field.ty;
+            (
+                quote! {
+                    let #binding: Option<#field_ty> = lombok_macros_runtime::parse_env_var(
+                        #var_name,
+                        #default,
+                        &mut __lombok_errors,
+                    );
+                },
+                true,
+            )
+        }
+    })
+}
+
+/// Generates a `from_env` constructor reading every field from an environment variable.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `from_env` implementation.
+pub(crate) fn inner_from_env(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let prefix: String = match parse_attribute_string(&input.attrs, ENV, PREFIX) {
+        Ok(prefix) => prefix.unwrap_or_default(),
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields.named.iter().collect(),
+            _ => panic!("{}", UNSUPPORTED_FROM_ENV_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_FROM_ENV_DERIVE),
+    };
+    let mut reads: Vec<TokenStream2> = Vec::new();
+    let mut required: Vec<Ident> = Vec::new();
+    let mut field_names: Vec<&Ident> = Vec::new();
+    let mut bindings: Vec<Ident> = Vec::new();
+    for field in fields {
+        let field_name: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+        let binding: Ident = format_ident!(
+            "__lombok_field_{}",
+            get_clean_attr_name(&field_name.to_string())
+        );
+        let (read, is_required): (TokenStream2, bool) =
+            match build_field_env_read(field, &binding, &prefix) {
+                Ok(read) => read,
+                Err(error) => return error.to_compile_error().into(),
+            };
+        reads.push(read);
+        if is_required {
+            required.push(binding.clone());
+        }
+        field_names.push(field_name);
+        bindings.push(binding);
+    }
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            #vis fn from_env() -> Result<Self, lombok_macros_runtime::EnvError> {
+                let mut __lombok_errors: Vec<lombok_macros_runtime::EnvVarError> = Vec::new();
+                #(#reads)*
+                match (#(#required,)*) {
+                    (#(Some(#required),)*) if __lombok_errors.is_empty() => Ok(Self {
+                        #(#field_names: #bindings),*
+                    }),
+                    _ => Err(lombok_macros_runtime::EnvError::new(__lombok_errors)),
+                }
+            }
+        }
+    };
+    expanded.into()
+}
@@ -6,6 +6,12 @@
 /// - `Set` - Represents a setter function.
 /// - `Debug` - Represents a debug function.
 /// - `New` - Represents a constructor function.
+/// - `FieldName` - Represents a field name constant.
+/// - `FieldAccess` - Represents dynamic field access by name.
+/// - `Visit` - Represents a field visitor function.
+/// - `Diff` - Represents a field diff function.
+/// - `Prop` - Represents a string-keyed property conversion.
+/// - `Builder` - Represents a typestate builder setter.
 /// - `Unknown` - Represents an unknown or unspecified function type.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub(crate) enum FuncType {
@@ -19,6 +25,18 @@ pub(crate) enum FuncType {
     Debug,
     /// Represents a constructor function.
     New,
+    /// Represents a field name constant.
+    FieldName,
+    /// Represents dynamic field access by name.
+    FieldAccess,
+    /// Represents a field visitor function.
+    Visit,
+    /// Represents a field diff function.
+    Diff,
+    /// Represents a string-keyed property conversion.
+    Prop,
+    /// Represents a typestate builder setter.
+    Builder,
     /// Represents an unknown or unspecified function type.
     #[default]
     Unknown,
@@ -62,6 +80,7 @@ pub(crate) enum ParameterType {
 /// - `Clone` - Returns a cloned copy of the field value (`T`).
 /// - `Copy` - Returns a copy of the field value for types implementing Copy trait (`self.field`).
 /// - `Deref` - Returns a dereferenced value of the field (`*field`), with match control for Option/Result.
+/// - `IntoOwned` - Returns the owned value of a `Cow<'a, B>` field (`B::Owned`).
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub(crate) enum ReturnType {
     /// Returns a reference to the field value (`&T`).
@@ -73,4 +92,6 @@ pub(crate) enum ReturnType {
     Copy,
     /// Returns a dereferenced value of the field (`*field`), with match control for Option/Result.
     Deref,
+    /// Returns the owned value of a `Cow<'a, B>` field (`B::Owned`).
+    IntoOwned,
 }
@@ -22,6 +22,12 @@ impl FromStr for FuncType {
             SET => Ok(FuncType::Set),
             DEBUG => Ok(FuncType::Debug),
             NEW => Ok(FuncType::New),
+            FIELD_NAME => Ok(FuncType::FieldName),
+            FIELD_ACCESS => Ok(FuncType::FieldAccess),
+            VISIT => Ok(FuncType::Visit),
+            DIFF => Ok(FuncType::Diff),
+            PROP => Ok(FuncType::Prop),
+            BUILDER => Ok(FuncType::Builder),
             _ => Ok(FuncType::Unknown),
         }
     }
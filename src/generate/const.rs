@@ -13,6 +13,123 @@ pub(crate) const OPTION_TYPE: &str = "Option";
 /// The Result type identifier.
 pub(crate) const RESULT_TYPE: &str = "Result";
 
+/// The OnceCell type identifier.
+pub(crate) const ONCE_CELL_TYPE: &str = "OnceCell";
+
+/// The OnceLock type identifier.
+pub(crate) const ONCE_LOCK_TYPE: &str = "OnceLock";
+
+/// The Cow type identifier.
+pub(crate) const COW_TYPE: &str = "Cow";
+
+/// The Cell type identifier.
+pub(crate) const CELL_TYPE: &str = "Cell";
+
+/// The RefCell type identifier.
+pub(crate) const REF_CELL_TYPE: &str = "RefCell";
+
+/// The Mutex type identifier.
+pub(crate) const MUTEX_TYPE: &str = "Mutex";
+
+/// The RwLock type identifier.
+pub(crate) const RW_LOCK_TYPE: &str = "RwLock";
+
+/// The crate roots under which the interior-mutability types are recognized.
+pub(crate) const INTERIOR_MUTABILITY_CRATES: [&str; 2] = ["std", "core"];
+
+/// The prefix shared by the `std::sync::atomic` type identifiers.
+pub(crate) const ATOMIC_TYPE_PREFIX: &str = "Atomic";
+
+/// The AtomicBool type identifier.
+pub(crate) const ATOMIC_BOOL_TYPE: &str = "AtomicBool";
+
+/// The AtomicPtr type identifier.
+pub(crate) const ATOMIC_PTR_TYPE: &str = "AtomicPtr";
+
+/// The integer types wrapped by the `std::sync::atomic` integer types.
+pub(crate) const ATOMIC_INTEGER_TYPES: [&str; 10] = [
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+/// The memory ordering used by atomic accessors when none is specified.
+pub(crate) const DEFAULT_ATOMIC_ORDERING: &str = "SeqCst";
+
+/// The memory orderings accepted by atomic loads.
+pub(crate) const ATOMIC_LOAD_ORDERINGS: [&str; 3] = ["Relaxed", "Acquire", "SeqCst"];
+
+/// The memory orderings accepted by atomic stores.
+pub(crate) const ATOMIC_STORE_ORDERINGS: [&str; 3] = ["Relaxed", "Release", "SeqCst"];
+
+/// Error message indicating that an atomic accessor was requested for a non-atomic field.
+pub(crate) const ATOMIC_ACCESSOR_REQUIRES_ATOMIC: &str =
+    "#[get(atomic(..))] and #[set(atomic(..))] require a std::sync::atomic field";
+
+/// Error message indicating that an atomic getter uses an ordering not valid for loads.
+pub(crate) const INVALID_ATOMIC_LOAD_ORDERING: &str =
+    "Atomic getters only support the Relaxed, Acquire and SeqCst orderings";
+
+/// Error message indicating that an atomic setter uses an ordering not valid for stores.
+pub(crate) const INVALID_ATOMIC_STORE_ORDERING: &str =
+    "Atomic setters only support the Relaxed, Release and SeqCst orderings";
+
+/// Error message indicating that `fetch_add` was requested for a non-integer atomic field.
+pub(crate) const FETCH_ADD_REQUIRES_ATOMIC_INTEGER: &str =
+    "#[set(atomic(..), fetch_add)] requires an atomic integer field";
+
+/// Error message indicating that an owning getter was requested for a field that is not a Cow.
+pub(crate) const INTO_OWNED_REQUIRES_COW: &str = "#[get(into_owned)] requires a Cow<'a, B> field";
+
+/// Error message indicating that unwrapping accessors were requested for a field that is not a standard interior-mutability type.
+pub(crate) const UNWRAP_REQUIRES_INTERIOR_MUTABILITY: &str = "#[get(unwrap)] and #[set(unwrap)] require a std Cell<T>, RefCell<T>, Mutex<T> or RwLock<T> field";
+
+/// Error message indicating that a serde `rename_all` rule is not recognized.
+pub(crate) const INVALID_SERDE_RENAME_RULE: &str = "Unknown #[serde(rename_all = ...)] rule, expected one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`";
+
+/// The serde `lowercase` rename rule.
+pub(crate) const SERDE_LOWERCASE: &str = "lowercase";
+
+/// The serde `UPPERCASE` rename rule.
+pub(crate) const SERDE_UPPERCASE: &str = "UPPERCASE";
+
+/// The serde `PascalCase` rename rule.
+pub(crate) const SERDE_PASCAL_CASE: &str = "PascalCase";
+
+/// The serde `camelCase` rename rule.
+pub(crate) const SERDE_CAMEL_CASE: &str = "camelCase";
+
+/// The serde `snake_case` rename rule.
+pub(crate) const SERDE_SNAKE_CASE: &str = "snake_case";
+
+/// The serde `SCREAMING_SNAKE_CASE` rename rule.
+pub(crate) const SERDE_SCREAMING_SNAKE_CASE: &str = "SCREAMING_SNAKE_CASE";
+
+/// The serde `kebab-case` rename rule.
+pub(crate) const SERDE_KEBAB_CASE: &str = "kebab-case";
+
+/// The serde `SCREAMING-KEBAB-CASE` rename rule.
+pub(crate) const SERDE_SCREAMING_KEBAB_CASE: &str = "SCREAMING-KEBAB-CASE";
+
+/// Error message indicating that a lazy getter was requested for a field that is not a OnceCell or OnceLock.
+pub(crate) const LAZY_GETTER_REQUIRES_ONCE_CELL: &str =
+    "#[get(lazy = ...)] requires a OnceCell<T> or OnceLock<T> field";
+
+/// Error message indicating that a computed getter declaration could not be parsed.
+pub(crate) const INVALID_COMPUTED_GETTER: &str =
+    "Invalid #[get(computed(...))] declaration, expected `name: Type = expression`";
+
+/// Error message indicating that a #[get(flatten)] field does not declare the getters it forwards.
+pub(crate) const GET_FLATTEN_REQUIRES_GETTERS: &str = "#[get(flatten(...))] requires the forwarded getters of the flattened type, such as `flatten(author: &String, version: &u32)`";
+
+/// Error message indicating that a #[new(flatten)] field does not declare the constructor parameters of its type.
+pub(crate) const NEW_FLATTEN_REQUIRES_PARAMS: &str = "#[new(flatten(...))] requires the constructor parameters of the flattened type, such as `flatten(author: String, version: u32)`";
+
+/// Error message indicating that a #[new(flatten(...))] parameter reuses the name of another constructor parameter.
+pub(crate) const DUPLICATE_NEW_PARAM: &str = "#[new(flatten(...))] parameter names must differ from the other constructor parameters, rename the duplicate";
+
+/// Error message indicating that the entries of a `flatten(...)` declaration could not be parsed.
+pub(crate) const INVALID_FLATTEN_PARAMS: &str =
+    "Invalid flatten(...) declaration, expected `name: Type` entries";
+
 /// Prefix for getter methods.
 pub(crate) const GET_METHOD_PREFIX: &str = "get_";
 
@@ -25,5 +142,23 @@ pub(crate) const SET_METHOD_PREFIX: &str = "set_";
 /// The string prefix used for raw identifiers.
 pub(crate) const RAW_IDENT_PREFIX: &str = "r#";
 
+/// Prefix for atomic fetch-add methods.
+pub(crate) const FETCH_ADD_METHOD_PREFIX: &str = "fetch_add_";
+
+/// Prefix for atomic increment methods.
+pub(crate) const INCREMENT_METHOD_PREFIX: &str = "increment_";
+
+/// The name of the method borrowing every field mutably at once.
+pub(crate) const SPLIT_MUT_METHOD: &str = "split_mut";
+
+/// Suffix of the struct returned by the split-borrow accessor.
+pub(crate) const SPLIT_MUT_STRUCT_SUFFIX: &str = "Mut";
+
+/// The lifetime of the borrows held by the struct returned by the split-borrow accessor.
+pub(crate) const SPLIT_MUT_LIFETIME: &str = "'split";
+
 /// The try_get method prefix.
 pub(crate) const TRY_GET_METHOD_PREFIX: &str = "try_";
+
+/// The try_set method prefix.
+pub(crate) const TRY_SET_METHOD_PREFIX: &str = "try_";
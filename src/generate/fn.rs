@@ -9,7 +9,7 @@ use crate::*;
 /// # Returns
 ///
 /// - `String` - The cleaned attribute name.
-fn get_clean_attr_name(attr_str: &str) -> String {
+pub(crate) fn get_clean_attr_name(attr_str: &str) -> String {
     let clean_attr: String = if let Some(stripped) = attr_str.strip_prefix(RAW_IDENT_PREFIX) {
         stripped.to_owned()
     } else {
@@ -18,6 +18,124 @@ fn get_clean_attr_name(attr_str: &str) -> String {
     clean_attr
 }
 
+/// Converts a snake_case identifier string into PascalCase.
+///
+/// # Arguments
+///
+/// - `&str` - The snake_case string to convert.
+///
+/// # Returns
+///
+/// - `String` - The PascalCase representation.
+pub(crate) fn to_pascal_case(snake_str: &str) -> String {
+    snake_str
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars: std::str::Chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts a PascalCase identifier string into snake_case.
+///
+/// An underscore is inserted before every uppercase letter that starts a new word,
+/// so acronyms stay together: `HTTPServer` becomes `http_server`.
+///
+/// # Arguments
+///
+/// - `&str` - The PascalCase string to convert.
+///
+/// # Returns
+///
+/// - `String` - The snake_case representation.
+pub(crate) fn to_snake_case(pascal_str: &str) -> String {
+    let chars: Vec<char> = pascal_str.chars().collect();
+    let mut snake: String = String::new();
+    for (index, current) in chars.iter().enumerate() {
+        if current.is_uppercase() && index > 0 {
+            let previous: char = chars[index - 1];
+            let next_is_lower: bool = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous != '_' && (!previous.is_uppercase() || next_is_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(current.to_lowercase());
+    }
+    snake
+}
+
+/// Applies a serde `rename_all` rule to a field or variant name, as serde does.
+///
+/// Variant names are split before every uppercase letter, so `HTTPServer` becomes
+/// `h_t_t_p_server` in snake_case, matching serde rather than `to_snake_case`.
+///
+/// # Arguments
+///
+/// - `&str` - The snake_case field name or PascalCase variant name.
+/// - `&str` - The rule, such as `camelCase` or `SCREAMING_SNAKE_CASE`.
+/// - `bool` - Whether the name is a variant name.
+///
+/// # Returns
+///
+/// - `String` - The renamed value.
+pub(crate) fn apply_serde_rename_rule(name: &str, rule: &str, is_variant: bool) -> String {
+    let snake: String = if is_variant {
+        let mut snake: String = String::new();
+        for (index, current) in name.char_indices() {
+            if index > 0 && current.is_uppercase() {
+                snake.push('_');
+            }
+            snake.push(current.to_ascii_lowercase());
+        }
+        snake
+    } else {
+        name.to_string()
+    };
+    match rule {
+        SERDE_LOWERCASE if is_variant => name.to_ascii_lowercase(),
+        SERDE_UPPERCASE if is_variant => name.to_ascii_uppercase(),
+        SERDE_LOWERCASE | SERDE_SNAKE_CASE => snake,
+        SERDE_UPPERCASE | SERDE_SCREAMING_SNAKE_CASE => snake.to_ascii_uppercase(),
+        SERDE_PASCAL_CASE => to_pascal_case(&snake),
+        SERDE_CAMEL_CASE => {
+            let pascal: String = to_pascal_case(&snake);
+            let mut chars: std::str::Chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        SERDE_KEBAB_CASE => snake.replace('_', "-"),
+        SERDE_SCREAMING_KEBAB_CASE => snake.to_ascii_uppercase().replace('_', "-"),
+        _ => panic!("{} `{}`.", INVALID_SERDE_RENAME_RULE, rule),
+    }
+}
+
+/// Adds a trait or lifetime bound to every type parameter of the given generics.
+///
+/// # Arguments
+///
+/// - `&Generics` - The generics of the derive input.
+/// - `TypeParamBound` - The bound to add, such as `'static` or `std::fmt::Debug`.
+///
+/// # Returns
+///
+/// - `Generics` - A copy of the generics with the bound added to all type parameters.
+pub(crate) fn add_type_param_bound(generics: &Generics, bound: TypeParamBound) -> Generics {
+    let mut generics: Generics = generics.clone();
+    for param in generics.params.iter_mut() {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(bound.clone());
+        }
+    }
+    generics
+}
+
 /// Checks if a type is an Option<T> type.
 ///
 /// # Arguments
@@ -27,7 +145,7 @@ fn get_clean_attr_name(attr_str: &str) -> String {
 /// # Returns
 ///
 /// - `bool` - true if the type is Option<T>, false otherwise.
-fn is_option_type(ty: &Type) -> bool {
+pub(crate) fn is_option_type(ty: &Type) -> bool {
     match ty {
         Type::Path(type_path) => {
             if let Some(segment) = type_path.path.segments.last() {
@@ -71,7 +189,7 @@ fn is_result_type(ty: &Type) -> bool {
 /// # Returns
 ///
 /// - `Option<Type>` - Some containing the inner type T, or None if extraction fails.
-fn extract_option_inner_type(ty: &Type) -> Option<Type> {
+pub(crate) fn extract_option_inner_type(ty: &Type) -> Option<Type> {
     match ty {
         Type::Path(type_path) => {
             if let Some(segment) = type_path.path.segments.last()
@@ -120,6 +238,195 @@ fn extract_result_types(ty: &Type) -> Option<(Type, Type)> {
     }
 }
 
+/// Extracts the inner type from a OnceCell<T> or OnceLock<T> type.
+///
+/// # Arguments
+///
+/// - `&Type` - The OnceCell or OnceLock type.
+///
+/// # Returns
+///
+/// - `Option<Type>` - Some containing the inner type T, or None if the type is not a OnceCell or OnceLock.
+fn extract_once_cell_inner_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(segment) = type_path.path.segments.last()
+                && (segment.ident == ONCE_CELL_TYPE || segment.ident == ONCE_LOCK_TYPE)
+                && let PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+            {
+                return Some(inner_ty.clone());
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Extracts the wrapper name and inner type T from a Cell<T>, RefCell<T>, Mutex<T> or RwLock<T> type.
+///
+/// Qualified paths are only recognized under `std` or `core`, so locks from other crates are rejected.
+///
+/// # Arguments
+///
+/// - `&Type` - The interior-mutability type.
+///
+/// # Returns
+///
+/// - `Option<(String, Type)>` - Some containing the wrapper name and inner type T, or None for other types.
+fn extract_interior_mutability_type(ty: &Type) -> Option<(String, Type)> {
+    match ty {
+        Type::Path(type_path) => {
+            let segments = &type_path.path.segments;
+            if let Some(root) = segments.first()
+                && (segments.len() == 1
+                    || INTERIOR_MUTABILITY_CRATES
+                        .iter()
+                        .any(|krate| root.ident == krate))
+                && let Some(segment) = segments.last()
+                && [CELL_TYPE, REF_CELL_TYPE, MUTEX_TYPE, RW_LOCK_TYPE]
+                    .iter()
+                    .any(|wrapper| segment.ident == wrapper)
+                && let PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+            {
+                return Some((segment.ident.to_string(), inner_ty.clone()));
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Extracts the value type of a `std::sync::atomic` type, such as `u64` for `AtomicU64`.
+///
+/// # Arguments
+///
+/// - `&Type` - The atomic type.
+///
+/// # Returns
+///
+/// - `Option<Type>` - Some containing the value type, or None if the type is not atomic.
+fn extract_atomic_value_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let ident: String = segment.ident.to_string();
+    if ident == ATOMIC_BOOL_TYPE {
+        return Some(parse_quote!(bool));
+    }
+    if ident == ATOMIC_PTR_TYPE {
+        if let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+        {
+            return Some(parse_quote!(*mut #inner_ty));
+        }
+        return None;
+    }
+    let integer: String = ident.strip_prefix(ATOMIC_TYPE_PREFIX)?.to_lowercase();
+    if ATOMIC_INTEGER_TYPES.contains(&integer.as_str()) {
+        parse2::<Type>(integer.parse().ok()?).ok()
+    } else {
+        None
+    }
+}
+
+/// Resolves the memory ordering of an atomic accessor from the field and container attributes.
+///
+/// A field-level `atomic` option requires an atomic field and takes precedence over the container
+/// ordering; a container ordering only applies to atomic fields. `SeqCst` is used when `atomic`
+/// is given without an ordering.
+///
+/// # Arguments
+///
+/// - `&Type` - The type of the field.
+/// - `Option<&TokenStream2>` - The ordering from the field attribute.
+/// - `Option<&TokenStream2>` - The ordering from the container attribute.
+/// - `&[&str]` - The orderings valid for this accessor.
+/// - `&str` - The error message used when the ordering is not valid.
+///
+/// # Returns
+///
+/// - `Option<Ident>` - The ordering variant, or None if the field should not get an atomic accessor.
+fn resolve_atomic_ordering(
+    attr_ty: &Type,
+    field_ordering: Option<&TokenStream2>,
+    container_ordering: Option<&TokenStream2>,
+    valid_orderings: &[&str],
+    invalid_ordering_error: &str,
+) -> Option<Ident> {
+    let is_atomic: bool = extract_atomic_value_type(attr_ty).is_some();
+    if field_ordering.is_some() && !is_atomic {
+        panic!("{}", ATOMIC_ACCESSOR_REQUIRES_ATOMIC);
+    }
+    if !is_atomic || field_ordering.is_none() && container_ordering.is_none() {
+        return None;
+    }
+    let ordering_str: String = field_ordering
+        .filter(|ordering| !ordering.is_empty())
+        .or(container_ordering)
+        .map(|ordering| ordering.to_string().replace(' ', ""))
+        .unwrap_or_default();
+    let ordering_name: &str = match ordering_str.rsplit("::").next() {
+        Some("") | None => DEFAULT_ATOMIC_ORDERING,
+        Some(name) => name,
+    };
+    if !valid_orderings.contains(&ordering_name) {
+        panic!("{} `{}`.", invalid_ordering_error, ordering_name);
+    }
+    Some(format_ident!("{}", ordering_name))
+}
+
+/// Extracts the borrowed type `B` from a `Cow<'a, B>` type.
+///
+/// # Arguments
+///
+/// - `&Type` - The type to extract from.
+///
+/// # Returns
+///
+/// - `Option<Type>` - The borrowed type, such as `str` or `[T]`, if the type is `Cow`.
+fn extract_cow_inner_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment: &PathSegment = type_path.path.segments.last()?;
+    if segment.ident != COW_TYPE {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(inner_ty) => Some(inner_ty.clone()),
+        _ => None,
+    })
+}
+
+/// Resolves the parameter type override of a setter.
+///
+/// `Cow` fields without a `type(...)` option accept `impl Into<Cow<'a, B>>`, so both borrowed
+/// and owned values can be passed.
+///
+/// # Arguments
+///
+/// - `&Type` - The type of the field.
+/// - `Option<&TokenStream2>` - The parameter type declared with `type(...)`.
+///
+/// # Returns
+///
+/// - `Option<TokenStream2>` - The declared override, `Into<Cow<'a, B>>` for `Cow` fields, or None.
+pub(crate) fn resolve_param_type_override(
+    field_type: &Type,
+    param_type_override: Option<&TokenStream2>,
+) -> Option<TokenStream2> {
+    match param_type_override {
+        Some(override_type) => Some(override_type.clone()),
+        None => extract_cow_inner_type(field_type).map(|_| quote! { Into<#field_type> }),
+    }
+}
+
 /// Checks if a type is a Box<T> type.
 ///
 /// # Arguments
@@ -196,7 +503,7 @@ fn is_arc_type(ty: &Type) -> bool {
 /// # Returns
 ///
 /// - `TokenStream2` - The generated parameter type as tokens.
-fn generate_param_type(
+pub(crate) fn generate_param_type(
     field_type: &Type,
     param_type_override: Option<&TokenStream2>,
 ) -> TokenStream2 {
@@ -233,6 +540,32 @@ fn generate_param_type(
     }
 }
 
+/// Generates the value expression converting the setter parameter into the field type.
+///
+/// # Arguments
+///
+/// - `param_type_override` - Optional custom parameter type from attribute specification.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated value expression.
+pub(crate) fn generate_param_value(param_type_override: Option<&TokenStream2>) -> TokenStream2 {
+    if let Some(override_type) = param_type_override {
+        let type_str: String = override_type.to_string();
+        let type_str_normalized: String = type_str.replace(' ', "");
+        match ParameterType::from(type_str_normalized.as_str()) {
+            ParameterType::AsRef => quote! { val.as_ref().to_owned() },
+            ParameterType::Into
+            | ParameterType::AsMut
+            | ParameterType::Deref
+            | ParameterType::Custom(_) => quote! { val.into() },
+            ParameterType::Direct => quote! { val },
+        }
+    } else {
+        quote! { val }
+    }
+}
+
 /// Generates the appropriate assignment expression based on the parameter type.
 ///
 /// # Arguments
@@ -338,6 +671,8 @@ fn generate_return_type(field_type: &Type, return_type: ReturnType) -> TokenStre
                 } else {
                     quote! { #field_type }
                 }
+            } else if let Some(inner_ty) = extract_cow_inner_type(field_type) {
+                quote! { &#inner_ty }
             } else {
                 quote! { &#field_type }
             }
@@ -345,6 +680,10 @@ fn generate_return_type(field_type: &Type, return_type: ReturnType) -> TokenStre
         ReturnType::Clone | ReturnType::Copy => {
             quote! { #field_type }
         }
+        ReturnType::IntoOwned => {
+            let inner_ty: Type = extract_cow_inner_type(field_type).expect(INTO_OWNED_REQUIRES_COW);
+            quote! { <#inner_ty as std::borrow::ToOwned>::Owned }
+        }
         ReturnType::Deref => {
             if is_option_type(field_type)
                 || is_result_type(field_type)
@@ -432,6 +771,12 @@ fn build_named_get_quote(
                 self.#attr_name_ident
             }
         },
+        ReturnType::IntoOwned => quote! {
+            #[inline(always)]
+            #vis fn #get_name(&self) -> #return_ty {
+                std::borrow::ToOwned::to_owned(&*self.#attr_name_ident)
+            }
+        },
         ReturnType::Deref => {
             if is_option_type(attr_ty) {
                 quote! {
@@ -479,6 +824,431 @@ fn build_named_get_quote(
     }
 }
 
+/// Generates a lazy getter function that initializes a `OnceCell`/`OnceLock` field on first access.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a getter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the getter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `&TokenStream2` - The path of the initializer, called with `self`.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated lazy getter function.
+fn build_lazy_get_quote(
+    need_getter: bool,
+    vis: TokenStream2,
+    get_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    lazy_init: &TokenStream2,
+) -> TokenStream2 {
+    if !need_getter {
+        return quote! {};
+    }
+    let inner_ty: Type =
+        extract_once_cell_inner_type(attr_ty).expect(LAZY_GETTER_REQUIRES_ONCE_CELL);
+    quote! {
+        #[inline(always)]
+        #vis fn #get_name(&self) -> &#inner_ty {
+            self.#member.get_or_init(|| #lazy_init(self))
+        }
+    }
+}
+
+/// Generates the `#[get(unwrap)]` getter of a `Cell`, `RefCell`, `Mutex` or `RwLock` field.
+///
+/// `Cell<T>` fields return a copy of `T`, `RefCell<T>` fields return a `Ref<T>` and lock fields
+/// return their guard, panicking if the lock is poisoned. Lock fields also get a `try_` getter.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a getter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the getter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated getter functions.
+fn build_interior_get_quote(
+    need_getter: bool,
+    vis: TokenStream2,
+    get_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+) -> TokenStream2 {
+    if !need_getter {
+        return quote! {};
+    }
+    let (wrapper, inner_ty) =
+        extract_interior_mutability_type(attr_ty).expect(UNWRAP_REQUIRES_INTERIOR_MUTABILITY);
+    let return_ty: TokenStream2 = generate_interior_return_type(&wrapper, &inner_ty);
+    let body: TokenStream2 = match wrapper.as_str() {
+        CELL_TYPE => quote! { self.#member.get() },
+        REF_CELL_TYPE => quote! { self.#member.borrow() },
+        MUTEX_TYPE => quote! {
+            match self.#member.lock() {
+                Ok(guard) => guard,
+                Err(err) => panic!("Failed to lock field '{}': {}", stringify!(#member), err),
+            }
+        },
+        _ => quote! {
+            match self.#member.read() {
+                Ok(guard) => guard,
+                Err(err) => panic!("Failed to lock field '{}': {}", stringify!(#member), err),
+            }
+        },
+    };
+    let try_get: TokenStream2 =
+        build_interior_try_get_quote(&vis, get_name, member, &wrapper, &return_ty);
+    quote! {
+        #[inline(always)]
+        #vis fn #get_name(&self) -> #return_ty {
+            #body
+        }
+
+        #try_get
+    }
+}
+
+/// Generates a try getter function for a `Mutex` or `RwLock` field that reports lock poisoning.
+///
+/// # Arguments
+///
+/// - `&TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the getter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&str` - The name of the wrapper type.
+/// - `&TokenStream2` - The guard type returned by the getter.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated try getter function, or nothing if the field is not a lock.
+fn build_interior_try_get_quote(
+    vis: &TokenStream2,
+    get_name: &Ident,
+    member: &Member,
+    wrapper: &str,
+    return_ty: &TokenStream2,
+) -> TokenStream2 {
+    let try_get_name: Ident = format_ident!("{}{}", TRY_GET_METHOD_PREFIX, get_name);
+    let lock: TokenStream2 = match wrapper {
+        MUTEX_TYPE => quote! { lock },
+        RW_LOCK_TYPE => quote! { read },
+        _ => return quote! {},
+    };
+    quote! {
+        #[inline(always)]
+        #vis fn #try_get_name(&self) -> std::sync::LockResult<#return_ty> {
+            self.#member.#lock()
+        }
+    }
+}
+
+/// Generates the type returned by the `#[get(unwrap)]` getter of an interior-mutability field.
+///
+/// # Arguments
+///
+/// - `&str` - The name of the wrapper type.
+/// - `&Type` - The inner type of the wrapper.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The inner type for `Cell`, or the borrow guard for the other wrappers.
+fn generate_interior_return_type(wrapper: &str, inner_ty: &Type) -> TokenStream2 {
+    match wrapper {
+        CELL_TYPE => quote! { #inner_ty },
+        REF_CELL_TYPE => quote! { std::cell::Ref<'_, #inner_ty> },
+        MUTEX_TYPE => quote! { std::sync::MutexGuard<'_, #inner_ty> },
+        _ => quote! { std::sync::RwLockReadGuard<'_, #inner_ty> },
+    }
+}
+
+/// Generates the `#[set(unwrap)]` setter taking `&self` for a `Cell`, `RefCell`, `Mutex` or `RwLock` field.
+///
+/// Lock fields additionally get a `try_set_` variant returning the poison error instead of panicking.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a setter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The field name used to derive the setter names.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `param_type_override` - Optional custom parameter type from attribute specification.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated setter functions.
+fn build_interior_set_quote(
+    need_setter: bool,
+    vis: TokenStream2,
+    set_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    param_type_override: Option<&TokenStream2>,
+) -> TokenStream2 {
+    if !need_setter {
+        return quote! {};
+    }
+    let (wrapper, inner_ty) =
+        extract_interior_mutability_type(attr_ty).expect(UNWRAP_REQUIRES_INTERIOR_MUTABILITY);
+    let param_type: TokenStream2 = generate_param_type(&inner_ty, param_type_override);
+    let value: TokenStream2 = generate_param_value(param_type_override);
+    let try_set_name: Ident = format_ident!("{}{}", TRY_SET_METHOD_PREFIX, set_name);
+    let (lock, guard_ty): (TokenStream2, TokenStream2) = match wrapper.as_str() {
+        CELL_TYPE => {
+            return quote! {
+                #[inline(always)]
+                #vis fn #set_name(&self, val: #param_type) -> &Self {
+                    self.#member.set(#value);
+                    self
+                }
+            };
+        }
+        REF_CELL_TYPE => {
+            return quote! {
+                #[inline(always)]
+                #vis fn #set_name(&self, val: #param_type) -> &Self {
+                    *self.#member.borrow_mut() = #value;
+                    self
+                }
+            };
+        }
+        MUTEX_TYPE => (
+            quote! { lock },
+            quote! { std::sync::MutexGuard<'_, #inner_ty> },
+        ),
+        _ => (
+            quote! { write },
+            quote! { std::sync::RwLockWriteGuard<'_, #inner_ty> },
+        ),
+    };
+    quote! {
+        #[inline(always)]
+        #vis fn #set_name(&self, val: #param_type) -> &Self {
+            match self.#member.#lock() {
+                Ok(mut guard) => *guard = #value,
+                Err(err) => panic!("Failed to lock field '{}': {}", stringify!(#member), err),
+            }
+            self
+        }
+
+        #[inline(always)]
+        #vis fn #try_set_name(&self, val: #param_type) -> Result<&Self, std::sync::PoisonError<#guard_ty>> {
+            *self.#member.#lock()? = #value;
+            Ok(self)
+        }
+    }
+}
+
+/// Generates a getter loading the value of an atomic field.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a getter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the getter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `&Ident` - The memory ordering of the load.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated getter function.
+fn build_atomic_get_quote(
+    need_getter: bool,
+    vis: TokenStream2,
+    get_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    ordering: &Ident,
+) -> TokenStream2 {
+    if !need_getter {
+        return quote! {};
+    }
+    let value_ty: Type = extract_atomic_value_type(attr_ty).expect(ATOMIC_ACCESSOR_REQUIRES_ATOMIC);
+    quote! {
+        #[inline(always)]
+        #vis fn #get_name(&self) -> #value_ty {
+            self.#member.load(std::sync::atomic::Ordering::#ordering)
+        }
+    }
+}
+
+/// Generates a setter storing into an atomic field, with optional `fetch_add_` and `increment_` helpers.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a setter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the setter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `&Ident` - The memory ordering of the store.
+/// - `Option<&str>` - The field name used for the helpers, if `fetch_add` was requested.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated setter and helper functions.
+fn build_atomic_set_quote(
+    need_setter: bool,
+    vis: TokenStream2,
+    set_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    ordering: &Ident,
+    fetch_add_name: Option<&str>,
+) -> TokenStream2 {
+    if !need_setter {
+        return quote! {};
+    }
+    let value_ty: Type = extract_atomic_value_type(attr_ty).expect(ATOMIC_ACCESSOR_REQUIRES_ATOMIC);
+    let mut generated: TokenStream2 = quote! {
+        #[inline(always)]
+        #vis fn #set_name(&self, val: #value_ty) -> &Self {
+            self.#member.store(val, std::sync::atomic::Ordering::#ordering);
+            self
+        }
+    };
+    if let Some(field_name) = fetch_add_name {
+        if !ATOMIC_INTEGER_TYPES.contains(&value_ty.to_token_stream().to_string().as_str()) {
+            panic!("{}", FETCH_ADD_REQUIRES_ATOMIC_INTEGER);
+        }
+        let fetch_add_name: Ident = format_ident!("{}{}", FETCH_ADD_METHOD_PREFIX, field_name);
+        let increment_name: Ident = format_ident!("{}{}", INCREMENT_METHOD_PREFIX, field_name);
+        generated.extend(quote! {
+            #[inline(always)]
+            #vis fn #fetch_add_name(&self, val: #value_ty) -> #value_ty {
+                self.#member.fetch_add(val, std::sync::atomic::Ordering::#ordering)
+            }
+
+            #[inline(always)]
+            #vis fn #increment_name(&self) -> #value_ty {
+                self.#member.fetch_add(1, std::sync::atomic::Ordering::#ordering)
+            }
+        });
+    }
+    generated
+}
+
+/// Generates the getter of a field, loading atomic fields that declare an ordering.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a getter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the getter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `ReturnType` - The return type of the getter function.
+/// - `Option<&TokenStream2>` - The ordering declared on the field.
+/// - `Option<&TokenStream2>` - The ordering declared on the container.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated getter function.
+#[allow(clippy::too_many_arguments)]
+fn build_get_quote(
+    need_getter: bool,
+    vis: TokenStream2,
+    get_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    return_type: ReturnType,
+    field_ordering: Option<&TokenStream2>,
+    container_ordering: Option<&TokenStream2>,
+) -> TokenStream2 {
+    if let Some(ordering) = resolve_atomic_ordering(
+        attr_ty,
+        field_ordering,
+        container_ordering,
+        &ATOMIC_LOAD_ORDERINGS,
+        INVALID_ATOMIC_LOAD_ORDERING,
+    ) {
+        return build_atomic_get_quote(need_getter, vis, get_name, member, attr_ty, &ordering);
+    }
+    match member {
+        Member::Named(ident) => {
+            build_named_get_quote(need_getter, vis, get_name, ident, attr_ty, return_type)
+        }
+        Member::Unnamed(index) => {
+            build_tuple_get_quote(need_getter, vis, get_name, index, attr_ty, return_type)
+        }
+    }
+}
+
+/// Generates the setter of a field, storing into atomic fields that declare an ordering.
+///
+/// # Arguments
+///
+/// - `bool` - Whether to generate a setter function.
+/// - `TokenStream2` - The visibility of the function.
+/// - `&Ident` - The name of the setter function.
+/// - `&Member` - The field name or tuple index.
+/// - `&Type` - The type of the field.
+/// - `Option<&TokenStream2>` - Optional custom parameter type from attribute specification.
+/// - `Option<&TokenStream2>` - The ordering declared on the field.
+/// - `Option<&TokenStream2>` - The ordering declared on the container.
+/// - `Option<&str>` - The field name used for the atomic helpers, if `fetch_add` was requested.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated setter functions.
+#[allow(clippy::too_many_arguments)]
+fn build_set_quote(
+    need_setter: bool,
+    vis: TokenStream2,
+    set_name: &Ident,
+    member: &Member,
+    attr_ty: &Type,
+    param_type_override: Option<&TokenStream2>,
+    field_ordering: Option<&TokenStream2>,
+    container_ordering: Option<&TokenStream2>,
+    fetch_add_name: Option<&str>,
+) -> TokenStream2 {
+    if let Some(ordering) = resolve_atomic_ordering(
+        attr_ty,
+        field_ordering,
+        container_ordering,
+        &ATOMIC_STORE_ORDERINGS,
+        INVALID_ATOMIC_STORE_ORDERING,
+    ) {
+        return build_atomic_set_quote(
+            need_setter,
+            vis,
+            set_name,
+            member,
+            attr_ty,
+            &ordering,
+            fetch_add_name,
+        );
+    }
+    match member {
+        Member::Named(ident) => build_named_set_quote(
+            need_setter,
+            vis,
+            set_name,
+            ident,
+            attr_ty,
+            param_type_override,
+        ),
+        Member::Unnamed(index) => build_tuple_set_quote(
+            need_setter,
+            vis,
+            set_name,
+            index,
+            attr_ty,
+            param_type_override,
+        ),
+    }
+}
+
 /// Generates a try getter function for named struct fields.
 ///
 /// # Arguments
@@ -512,7 +1282,7 @@ fn build_named_try_get_quote(
                 &self.#attr_name_ident
             }
         },
-        ReturnType::Clone => quote! {
+        ReturnType::Clone | ReturnType::IntoOwned => quote! {
             #[inline(always)]
             #vis fn #try_get_name(&self) -> #attr_ty {
                 self.#attr_name_ident.clone()
@@ -605,8 +1375,11 @@ fn build_named_set_quote(
     param_type_override: Option<&TokenStream2>,
 ) -> TokenStream2 {
     if need_setter {
-        let param_type: TokenStream2 = generate_param_type(attr_ty, param_type_override);
-        let assignment: TokenStream2 = generate_assignment(attr_name_ident, param_type_override);
+        let param_type_override: Option<TokenStream2> =
+            resolve_param_type_override(attr_ty, param_type_override);
+        let param_type: TokenStream2 = generate_param_type(attr_ty, param_type_override.as_ref());
+        let assignment: TokenStream2 =
+            generate_assignment(attr_name_ident, param_type_override.as_ref());
         quote! {
             #[inline(always)]
             #vis fn #set_name(&mut self, val: #param_type) -> &mut Self {
@@ -627,6 +1400,7 @@ fn build_named_set_quote(
 /// - `bool` - Whether to generate a getter function.
 /// - `bool` - Whether to generate a mutable getter function.
 /// - `bool` - Whether to generate a setter function.
+/// - `&ContainerAtomicOrdering` - The container-level orderings for atomic fields.
 ///
 /// # Returns
 ///
@@ -636,6 +1410,7 @@ fn generate_named_getter_setter(
     need_getter: bool,
     need_getter_mut: bool,
     need_setter: bool,
+    atomic_ordering: &ContainerAtomicOrdering,
 ) -> TokenStream2 {
     let attr_name_ident: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
     let attr_ty: &Type = &field.ty;
@@ -675,22 +1450,43 @@ fn generate_named_getter_setter(
                 && !config.skip_flags.contains(&FuncType::Get)
                 && !shared_config.added_flags.contains(&FuncType::Get)
             {
-                generated.extend(build_named_get_quote(
-                    need_getter,
-                    vis.clone(),
-                    &get_name,
-                    attr_name_ident,
-                    attr_ty,
-                    config.return_type,
-                ));
-                generated.extend(build_named_try_get_quote(
-                    need_getter,
-                    vis.clone(),
-                    &get_name,
-                    attr_name_ident,
-                    attr_ty,
-                    config.return_type,
-                ));
+                if let Some(lazy_init) = &config.lazy_init {
+                    generated.extend(build_lazy_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Named(attr_name_ident.clone()),
+                        attr_ty,
+                        lazy_init,
+                    ));
+                } else if config.unwrap {
+                    generated.extend(build_interior_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Named(attr_name_ident.clone()),
+                        attr_ty,
+                    ));
+                } else {
+                    generated.extend(build_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Named(attr_name_ident.clone()),
+                        attr_ty,
+                        config.return_type,
+                        config.atomic_ordering.as_ref(),
+                        atomic_ordering.load.as_ref(),
+                    ));
+                    generated.extend(build_named_try_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        attr_name_ident,
+                        attr_ty,
+                        config.return_type,
+                    ));
+                }
                 shared_config.added_flags.insert(FuncType::Get);
             }
             if config.func_type.is_get_mut()
@@ -710,14 +1506,28 @@ fn generate_named_getter_setter(
                 && !config.skip_flags.contains(&FuncType::Set)
                 && !shared_config.added_flags.contains(&FuncType::Set)
             {
-                generated.extend(build_named_set_quote(
-                    need_setter,
-                    vis.clone(),
-                    &set_name,
-                    attr_name_ident,
-                    attr_ty,
-                    config.param_type_override.as_ref(),
-                ));
+                if config.unwrap {
+                    generated.extend(build_interior_set_quote(
+                        need_setter,
+                        vis.clone(),
+                        &set_name,
+                        &Member::Named(attr_name_ident.clone()),
+                        attr_ty,
+                        config.param_type_override.as_ref(),
+                    ));
+                } else {
+                    generated.extend(build_set_quote(
+                        need_setter,
+                        vis.clone(),
+                        &set_name,
+                        &Member::Named(attr_name_ident.clone()),
+                        attr_ty,
+                        config.param_type_override.as_ref(),
+                        config.atomic_ordering.as_ref(),
+                        atomic_ordering.store.as_ref(),
+                        config.fetch_add.then_some(&clean_attr_name),
+                    ));
+                }
                 shared_config.added_flags.insert(FuncType::Set);
             }
         }
@@ -728,13 +1538,15 @@ fn generate_named_getter_setter(
     {
         let vis: TokenStream2 = shared_config.visibility.to_token_stream();
         if !shared_config.added_flags.contains(&FuncType::Get) {
-            generated.extend(build_named_get_quote(
+            generated.extend(build_get_quote(
                 need_getter,
                 vis.clone(),
                 &get_name,
-                attr_name_ident,
+                &Member::Named(attr_name_ident.clone()),
                 attr_ty,
                 shared_config.return_type,
+                None,
+                atomic_ordering.load.as_ref(),
             ));
             generated.extend(build_named_try_get_quote(
                 need_getter,
@@ -755,19 +1567,162 @@ fn generate_named_getter_setter(
             ));
         }
         if !shared_config.added_flags.contains(&FuncType::Set) {
-            generated.extend(build_named_set_quote(
+            generated.extend(build_set_quote(
                 need_setter,
                 vis.clone(),
                 &set_name,
-                attr_name_ident,
+                &Member::Named(attr_name_ident.clone()),
                 attr_ty,
                 None,
+                None,
+                atomic_ordering.store.as_ref(),
+                None,
             ));
         }
     }
     generated
 }
 
+/// Resolves the getter name and the first `#[get(...)]` configuration of a named field.
+///
+/// # Arguments
+///
+/// - `&Field` - The named field.
+///
+/// # Returns
+///
+/// - `(Ident, Config)` - The getter name and the getter configuration.
+fn resolve_named_getter_config(field: &Field) -> (Ident, Config) {
+    let attr_name_ident: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+    let get_name: Ident = format_ident!(
+        "{}{}",
+        GET_METHOD_PREFIX,
+        get_clean_attr_name(&attr_name_ident.to_string())
+    );
+    let config: Config = field
+        .attrs
+        .iter()
+        .map(|attr| analyze_attributes(attr.to_token_stream()))
+        .find(|config| config.func_type.is_get())
+        .unwrap_or_default();
+    (get_name, config)
+}
+
+/// Generates the getter of a named field without a visibility modifier.
+///
+/// The getter follows the first `#[get(...)]` attribute of the field, exactly as
+/// `generate_named_getter_setter` does, so lazy, atomic and interior-mutability fields
+/// keep their specialized getters.
+///
+/// # Arguments
+///
+/// - `&Field` - The named field for which to generate the getter.
+/// - `&ContainerAtomicOrdering` - The container-level orderings for atomic fields.
+///
+/// # Returns
+///
+/// - `Option<TokenStream2>` - The generated getter, or None if the field has `#[get(skip)]`.
+pub(crate) fn generate_named_getter(
+    field: &Field,
+    atomic_ordering: &ContainerAtomicOrdering,
+) -> Option<TokenStream2> {
+    if should_skip_field(field, FuncType::Get) {
+        return None;
+    }
+    let attr_name_ident: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+    let attr_ty: &Type = &field.ty;
+    let (get_name, config): (Ident, Config) = resolve_named_getter_config(field);
+    let member: Member = Member::Named(attr_name_ident.clone());
+    if let Some(lazy_init) = &config.lazy_init {
+        return Some(build_lazy_get_quote(
+            true,
+            quote! {},
+            &get_name,
+            &member,
+            attr_ty,
+            lazy_init,
+        ));
+    }
+    if config.unwrap {
+        return Some(build_interior_get_quote(
+            true,
+            quote! {},
+            &get_name,
+            &member,
+            attr_ty,
+        ));
+    }
+    Some(build_get_quote(
+        true,
+        quote! {},
+        &get_name,
+        &member,
+        attr_ty,
+        config.return_type,
+        config.atomic_ordering.as_ref(),
+        atomic_ordering.load.as_ref(),
+    ))
+}
+
+/// Generates the trait method declarations matching `generate_named_getter`.
+///
+/// The return type is derived from the field type and the `#[get(...)]` options,
+/// and lock fields declared with `#[get(unwrap)]` also declare their `try_` getter.
+///
+/// # Arguments
+///
+/// - `&Field` - The named field for which to declare the getter.
+/// - `&ContainerAtomicOrdering` - The container-level orderings for atomic fields.
+///
+/// # Returns
+///
+/// - `Option<TokenStream2>` - The getter declarations, or None if the field has `#[get(skip)]`.
+pub(crate) fn generate_named_getter_signature(
+    field: &Field,
+    atomic_ordering: &ContainerAtomicOrdering,
+) -> Option<TokenStream2> {
+    if should_skip_field(field, FuncType::Get) {
+        return None;
+    }
+    let attr_ty: &Type = &field.ty;
+    let (get_name, config): (Ident, Config) = resolve_named_getter_config(field);
+    if config.lazy_init.is_some() {
+        let inner_ty: Type =
+            extract_once_cell_inner_type(attr_ty).expect(LAZY_GETTER_REQUIRES_ONCE_CELL);
+        return Some(quote! { fn #get_name(&self) -> &#inner_ty; });
+    }
+    if config.unwrap {
+        let (wrapper, inner_ty) =
+            extract_interior_mutability_type(attr_ty).expect(UNWRAP_REQUIRES_INTERIOR_MUTABILITY);
+        let return_ty: TokenStream2 = generate_interior_return_type(&wrapper, &inner_ty);
+        let try_get: TokenStream2 = if wrapper == MUTEX_TYPE || wrapper == RW_LOCK_TYPE {
+            let try_get_name: Ident = format_ident!("{}{}", TRY_GET_METHOD_PREFIX, get_name);
+            quote! { fn #try_get_name(&self) -> std::sync::LockResult<#return_ty>; }
+        } else {
+            quote! {}
+        };
+        return Some(quote! {
+            fn #get_name(&self) -> #return_ty;
+            #try_get
+        });
+    }
+    let return_ty: TokenStream2 = match resolve_atomic_ordering(
+        attr_ty,
+        config.atomic_ordering.as_ref(),
+        atomic_ordering.load.as_ref(),
+        &ATOMIC_LOAD_ORDERINGS,
+        INVALID_ATOMIC_LOAD_ORDERING,
+    ) {
+        Some(_) => {
+            let value_ty: Type =
+                extract_atomic_value_type(attr_ty).expect(ATOMIC_ACCESSOR_REQUIRES_ATOMIC);
+            quote! { #value_ty }
+        }
+        None => generate_return_type(attr_ty, config.return_type),
+    };
+    Some(quote! { fn #get_name(&self) -> #return_ty; })
+}
+
 /// Generates a getter function for tuple struct fields.
 ///
 /// # Arguments
@@ -824,6 +1779,12 @@ fn build_tuple_get_quote(
                 self.#field_index
             }
         },
+        ReturnType::IntoOwned => quote! {
+            #[inline(always)]
+            #vis fn #get_name(&self) -> #return_ty {
+                std::borrow::ToOwned::to_owned(&*self.#field_index)
+            }
+        },
         ReturnType::Deref => {
             if is_option_type(attr_ty) {
                 quote! {
@@ -890,7 +1851,7 @@ fn build_tuple_try_get_quote(
                 &self.#field_index
             }
         },
-        ReturnType::Clone => quote! {
+        ReturnType::Clone | ReturnType::IntoOwned => quote! {
             #[inline(always)]
             #vis fn #try_get_name(&self) -> #attr_ty {
                 self.#field_index.clone()
@@ -984,8 +1945,11 @@ fn build_tuple_set_quote(
     param_type_override: Option<&TokenStream2>,
 ) -> TokenStream2 {
     if need_setter {
-        let param_type: TokenStream2 = generate_param_type(attr_ty, param_type_override);
-        let assignment: TokenStream2 = generate_assignment_tuple(field_index, param_type_override);
+        let param_type_override: Option<TokenStream2> =
+            resolve_param_type_override(attr_ty, param_type_override);
+        let param_type: TokenStream2 = generate_param_type(attr_ty, param_type_override.as_ref());
+        let assignment: TokenStream2 =
+            generate_assignment_tuple(field_index, param_type_override.as_ref());
         quote! {
             #[inline(always)]
             #vis fn #set_name(&mut self, val: #param_type) -> &mut Self {
@@ -1007,6 +1971,7 @@ fn build_tuple_set_quote(
 /// - `bool` - Whether to generate a getter function.
 /// - `bool` - Whether to generate a mutable getter function.
 /// - `bool` - Whether to generate a setter function.
+/// - `&ContainerAtomicOrdering` - The container-level orderings for atomic fields.
 ///
 /// # Returns
 ///
@@ -1017,6 +1982,7 @@ fn generate_tuple_getter_setter(
     need_getter: bool,
     need_getter_mut: bool,
     need_setter: bool,
+    atomic_ordering: &ContainerAtomicOrdering,
 ) -> TokenStream2 {
     let attr_ty: &Type = &field.ty;
     let get_name: Ident = format_ident!("{}{}", GET_METHOD_PREFIX, index);
@@ -1055,22 +2021,43 @@ fn generate_tuple_getter_setter(
                 && !config.skip_flags.contains(&FuncType::Get)
                 && !shared_config.added_flags.contains(&FuncType::Get)
             {
-                generated.extend(build_tuple_get_quote(
-                    need_getter,
-                    vis.clone(),
-                    &get_name,
-                    &field_index,
-                    attr_ty,
-                    config.return_type,
-                ));
-                generated.extend(build_tuple_try_get_quote(
-                    need_getter,
-                    vis.clone(),
-                    &get_name,
-                    &field_index,
-                    attr_ty,
-                    config.return_type,
-                ));
+                if let Some(lazy_init) = &config.lazy_init {
+                    generated.extend(build_lazy_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Unnamed(field_index.clone()),
+                        attr_ty,
+                        lazy_init,
+                    ));
+                } else if config.unwrap {
+                    generated.extend(build_interior_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Unnamed(field_index.clone()),
+                        attr_ty,
+                    ));
+                } else {
+                    generated.extend(build_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &Member::Unnamed(field_index.clone()),
+                        attr_ty,
+                        config.return_type,
+                        config.atomic_ordering.as_ref(),
+                        atomic_ordering.load.as_ref(),
+                    ));
+                    generated.extend(build_tuple_try_get_quote(
+                        need_getter,
+                        vis.clone(),
+                        &get_name,
+                        &field_index,
+                        attr_ty,
+                        config.return_type,
+                    ));
+                }
                 shared_config.added_flags.insert(FuncType::Get);
             }
             if config.func_type.is_get_mut()
@@ -1090,14 +2077,28 @@ fn generate_tuple_getter_setter(
                 && !config.skip_flags.contains(&FuncType::Set)
                 && !shared_config.added_flags.contains(&FuncType::Set)
             {
-                generated.extend(build_tuple_set_quote(
-                    need_setter,
-                    vis.clone(),
-                    &set_name,
-                    &field_index,
-                    attr_ty,
-                    config.param_type_override.as_ref(),
-                ));
+                if config.unwrap {
+                    generated.extend(build_interior_set_quote(
+                        need_setter,
+                        vis.clone(),
+                        &set_name,
+                        &Member::Unnamed(field_index.clone()),
+                        attr_ty,
+                        config.param_type_override.as_ref(),
+                    ));
+                } else {
+                    generated.extend(build_set_quote(
+                        need_setter,
+                        vis.clone(),
+                        &set_name,
+                        &Member::Unnamed(field_index.clone()),
+                        attr_ty,
+                        config.param_type_override.as_ref(),
+                        config.atomic_ordering.as_ref(),
+                        atomic_ordering.store.as_ref(),
+                        config.fetch_add.then_some(&index.to_string()),
+                    ));
+                }
                 shared_config.added_flags.insert(FuncType::Set);
             }
         }
@@ -1108,13 +2109,15 @@ fn generate_tuple_getter_setter(
     {
         let vis: TokenStream2 = shared_config.visibility.to_token_stream();
         if !shared_config.added_flags.contains(&FuncType::Get) {
-            generated.extend(build_tuple_get_quote(
+            generated.extend(build_get_quote(
                 need_getter,
                 vis.clone(),
                 &get_name,
-                &field_index,
+                &Member::Unnamed(field_index.clone()),
                 attr_ty,
                 shared_config.return_type,
+                None,
+                atomic_ordering.load.as_ref(),
             ));
             generated.extend(build_tuple_try_get_quote(
                 need_getter,
@@ -1135,13 +2138,16 @@ fn generate_tuple_getter_setter(
             ));
         }
         if !shared_config.added_flags.contains(&FuncType::Set) {
-            generated.extend(build_tuple_set_quote(
+            generated.extend(build_set_quote(
                 need_setter,
                 vis.clone(),
                 &set_name,
-                &field_index,
+                &Member::Unnamed(field_index.clone()),
                 attr_ty,
                 None,
+                None,
+                atomic_ordering.store.as_ref(),
+                None,
             ));
         }
     }
@@ -1157,6 +2163,7 @@ fn generate_tuple_getter_setter(
 /// - `bool` - Whether to generate a getter function.
 /// - `bool` - Whether to generate a mutable getter function.
 /// - `bool` - Whether to generate a setter function.
+/// - `&ContainerAtomicOrdering` - The container-level orderings for atomic fields.
 ///
 /// # Returns
 ///
@@ -1167,11 +2174,253 @@ pub(crate) fn generate_getter_setter(
     need_getter: bool,
     need_getter_mut: bool,
     need_setter: bool,
+    atomic_ordering: &ContainerAtomicOrdering,
 ) -> TokenStream2 {
     if let Some(index) = field_index {
-        generate_tuple_getter_setter(field, index, need_getter, need_getter_mut, need_setter)
+        generate_tuple_getter_setter(
+            field,
+            index,
+            need_getter,
+            need_getter_mut,
+            need_setter,
+            atomic_ordering,
+        )
     } else {
-        generate_named_getter_setter(field, need_getter, need_getter_mut, need_setter)
+        generate_named_getter_setter(
+            field,
+            need_getter,
+            need_getter_mut,
+            need_setter,
+            atomic_ordering,
+        )
+    }
+}
+
+/// Generates the getters declared with `#[get(computed(name: Type = expr))]` on a struct.
+///
+/// # Arguments
+///
+/// - `&[Attribute]` - The container attributes of the struct.
+///
+/// # Returns
+///
+/// - `Vec<TokenStream2>` - One getter per computed declaration.
+fn generate_computed_getters(attrs: &[Attribute]) -> Vec<TokenStream2> {
+    let mut generated: Vec<TokenStream2> = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident(GET) {
+            continue;
+        }
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut option_tokens: TokenStream2 = TokenStream2::new();
+        let mut computed_groups: Vec<TokenStream2> = Vec::new();
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        while let Some(token) = tokens_iter.next() {
+            if let TokenTree2::Ident(ident) = &token
+                && ident == COMPUTED
+                && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                && group.delimiter() == Delimiter::Parenthesis
+            {
+                computed_groups.push(group.stream());
+                tokens_iter.next();
+                continue;
+            }
+            option_tokens.extend(std::iter::once(token));
+        }
+        let config: Config = analyze_attributes(option_tokens);
+        let vis: TokenStream2 = config.visibility.to_token_stream();
+        for computed_group in computed_groups {
+            let computed_getters: Punctuated<ComputedGetter, Token![,]> =
+                Punctuated::<ComputedGetter, Token![,]>::parse_terminated
+                    .parse2(computed_group)
+                    .unwrap_or_else(|error| panic!("{}: {}", INVALID_COMPUTED_GETTER, error));
+            for computed_getter in computed_getters {
+                let get_name: Ident = format_ident!(
+                    "{}{}",
+                    GET_METHOD_PREFIX,
+                    get_clean_attr_name(&computed_getter.name.to_string())
+                );
+                let return_ty: &Type = &computed_getter.ty;
+                let expr: &TokenStream2 = &computed_getter.expr;
+                generated.push(quote! {
+                    #[inline(always)]
+                    #vis fn #get_name(&self) -> #return_ty {
+                        #expr
+                    }
+                });
+            }
+        }
+    }
+    generated
+}
+
+/// Generates the getters forwarded to the fields marked `#[get(flatten(name: Type, ...))]`.
+///
+/// Every entry produces `get_name(&self) -> Type` on the struct, calling the getter of the
+/// same name on the flattened field, with the visibility of the field's `get` attribute.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The struct to generate the getters for.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated forwarding getters, or nothing without a `#[get(flatten(...))]` field.
+fn generate_get_flatten(input: &DeriveInput) -> TokenStream2 {
+    let Data::Struct(data_struct) = &input.data else {
+        return quote! {};
+    };
+    let mut getters: Vec<TokenStream2> = Vec::new();
+    for (index, field) in data_struct.fields.iter().enumerate() {
+        let Some(flatten_params) = parse_flatten_params(field, GET, GET_FLATTEN_REQUIRES_GETTERS)
+        else {
+            continue;
+        };
+        let member: Member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let mut config: Config = Config::default();
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(GET)) {
+            parse_tokens(attr.to_token_stream(), &mut config);
+        }
+        let vis: TokenStream2 = config.visibility.to_token_stream();
+        for FlattenParam { name, ty } in flatten_params {
+            let get_name: Ident = format_ident!("{}{}", GET_METHOD_PREFIX, name);
+            getters.push(quote! {
+                #[inline(always)]
+                #vis fn #get_name(&self) -> #ty {
+                    self.#member.#get_name()
+                }
+            });
+        }
+    }
+    if getters.is_empty() {
+        return quote! {};
+    }
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    }
+}
+
+/// Generates the arguments naming every generic parameter, such as `'a, T, N` for `<'a, T: Clone, const N: usize>`.
+///
+/// # Arguments
+///
+/// - `&Generics` - The generics of the derive input.
+///
+/// # Returns
+///
+/// - `Vec<TokenStream2>` - One argument per generic parameter, in declaration order.
+pub(crate) fn generate_generic_args(generics: &Generics) -> Vec<TokenStream2> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(lifetime_param) => lifetime_param.lifetime.to_token_stream(),
+            GenericParam::Type(type_param) => type_param.ident.to_token_stream(),
+            GenericParam::Const(const_param) => const_param.ident.to_token_stream(),
+        })
+        .collect()
+}
+
+/// Generates the `split_mut` accessor requested with `#[get_mut(split)]` on a struct.
+///
+/// The accessor returns a `{Struct}Mut` struct holding a `&mut` reference to every field
+/// without `#[get_mut(skip)]`, so several fields can be mutated in the same scope. Named
+/// structs produce named fields and tuple structs produce positional ones. A trailing
+/// `PhantomData` keeps the struct's generics and the borrow lifetime in use.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The struct to generate the accessor for.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated struct and accessor, or nothing without `#[get_mut(split)]`.
+fn generate_split_mut(input: &DeriveInput) -> TokenStream2 {
+    if !parse_attribute_flags(&input.attrs, GET_MUT)
+        .iter()
+        .any(|flag| flag == SPLIT)
+    {
+        return quote! {};
+    }
+    let Data::Struct(data_struct) = &input.data else {
+        return quote! {};
+    };
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let split_name: Ident = format_ident!("{}{}", name, SPLIT_MUT_STRUCT_SUFFIX);
+    let split_method: Ident = format_ident!("{}", SPLIT_MUT_METHOD);
+    let lifetime: Lifetime = Lifetime::new(SPLIT_MUT_LIFETIME, Span::call_site());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut split_generics: Generics = input.generics.clone();
+    split_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+    );
+    let (split_impl_generics, _, _) = split_generics.split_for_impl();
+    let generic_args: Vec<TokenStream2> = generate_generic_args(&input.generics);
+    let marker_ty: TokenStream2 = quote! {
+        std::marker::PhantomData<&#lifetime mut #name #ty_generics>
+    };
+    let split_fields: Vec<(Member, &Type)> = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !should_skip_field(field, FuncType::GetMut))
+        .map(|(index, field)| {
+            let member: Member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+            (member, &field.ty)
+        })
+        .collect();
+    let members: Vec<&Member> = split_fields.iter().map(|(member, _)| member).collect();
+    let tys: Vec<&Type> = split_fields.iter().map(|(_, ty)| *ty).collect();
+    let (split_struct, split_value): (TokenStream2, TokenStream2) = match &data_struct.fields {
+        Fields::Unnamed(_) => (
+            quote! {
+                #vis struct #split_name #split_impl_generics (
+                    #(#vis &#lifetime mut #tys,)*
+                    #marker_ty,
+                ) #where_clause;
+            },
+            quote! {
+                #split_name(#(&mut self.#members,)* std::marker::PhantomData)
+            },
+        ),
+        _ => (
+            quote! {
+                #vis struct #split_name #split_impl_generics #where_clause {
+                    #(#vis #members: &#lifetime mut #tys,)*
+                    _marker: #marker_ty,
+                }
+            },
+            quote! {
+                #split_name {
+                    #(#members: &mut self.#members,)*
+                    _marker: std::marker::PhantomData,
+                }
+            },
+        ),
+    };
+    quote! {
+        #split_struct
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[inline(always)]
+            #vis fn #split_method(&mut self) -> #split_name<'_, #(#generic_args),*> {
+                #split_value
+            }
+        }
     }
 }
 
@@ -1232,13 +2481,21 @@ pub(crate) fn inner_lombok_data(
         })
         .collect();
     let where_clause: &Option<WhereClause> = &input.generics.where_clause;
-    let methods: Vec<TokenStream2> = match input.data {
+    let atomic_ordering: ContainerAtomicOrdering = parse_container_atomic_ordering(&input.attrs);
+    let mut methods: Vec<TokenStream2> = match input.data {
         Data::Struct(ref s) => match &s.fields {
             Fields::Named(_) => s
                 .fields
                 .iter()
                 .map(|field| {
-                    generate_getter_setter(field, None, need_getter, need_getter_mut, need_setter)
+                    generate_getter_setter(
+                        field,
+                        None,
+                        need_getter,
+                        need_getter_mut,
+                        need_setter,
+                        &atomic_ordering,
+                    )
                 })
                 .collect::<Vec<_>>(),
             Fields::Unnamed(_) => s
@@ -1252,6 +2509,7 @@ pub(crate) fn inner_lombok_data(
                         need_getter,
                         need_getter_mut,
                         need_setter,
+                        &atomic_ordering,
                     )
                 })
                 .collect::<Vec<_>>(),
@@ -1259,6 +2517,19 @@ pub(crate) fn inner_lombok_data(
         },
         _ => panic!("{}", UNSUPPORTED_DATA_DERIVE),
     };
+    if need_getter {
+        methods.extend(generate_computed_getters(&input.attrs));
+    }
+    let get_flatten: TokenStream2 = if need_getter {
+        generate_get_flatten(&input)
+    } else {
+        quote! {}
+    };
+    let split_mut: TokenStream2 = if need_getter_mut {
+        generate_split_mut(&input)
+    } else {
+        quote! {}
+    };
     let expanded: TokenStream2 = if lifetimes.is_empty() {
         if type_bounds.is_empty() {
             quote! {
@@ -1293,7 +2564,12 @@ pub(crate) fn inner_lombok_data(
             }
         }
     };
-    expanded.into()
+    quote! {
+        #expanded
+        #get_flatten
+        #split_mut
+    }
+    .into()
 }
 
 /// Implements the `std::fmt::Display` trait for a given struct or enum.
@@ -1371,6 +2647,10 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
     let name: &Ident = &input.ident;
     let generics: &Generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let serde_names: bool = has_serde_names(&input.attrs);
+    let rename_all: Option<String> = serde_names
+        .then(|| parse_serde_rename_all(&input.attrs))
+        .flatten();
     match &input.data {
         Data::Struct(data_struct) => {
             let fields: &Fields = &data_struct.fields;
@@ -1390,10 +2670,20 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
                                     break;
                                 }
                             }
-                            if should_skip {
+                            if should_skip || serde_names && is_serde_skipped(&field.attrs) {
                                 None
                             } else {
-                                let field_name_str: String = field_name.to_string();
+                                let field_name_str: String = serde_names
+                                    .then(|| {
+                                        resolve_serde_name(
+                                            &field.attrs,
+                                            &field_name.to_string(),
+                                            rename_all.as_deref(),
+                                            false,
+                                        )
+                                    })
+                                    .flatten()
+                                    .unwrap_or_else(|| field_name.to_string());
                                 Some(quote! {
                                     .field(#field_name_str, &self.#field_name)
                                 })
@@ -1427,7 +2717,7 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
                                     break;
                                 }
                             }
-                            if should_skip {
+                            if should_skip || serde_names && is_serde_skipped(&field.attrs) {
                                 None
                             } else {
                                 let field_index: Index = Index::from(i);
@@ -1468,7 +2758,20 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
                 .iter()
                 .map(|variant: &Variant| {
                     let variant_name: &Ident = &variant.ident;
-                    let variant_name_str: String = variant_name.to_string();
+                    let variant_name_str: String = serde_names
+                        .then(|| {
+                            resolve_serde_name(
+                                &variant.attrs,
+                                &variant_name.to_string(),
+                                rename_all.as_deref(),
+                                true,
+                            )
+                        })
+                        .flatten()
+                        .unwrap_or_else(|| variant_name.to_string());
+                    let variant_rename_all: Option<String> = serde_names
+                        .then(|| parse_serde_rename_all(&variant.attrs))
+                        .flatten();
                     match &variant.fields {
                         Fields::Named(fields_named) => {
                             let field_patterns: Vec<TokenStream2> = fields_named
@@ -1495,10 +2798,21 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
                                             break;
                                         }
                                     }
-                                    if should_skip {
+                                    if should_skip || serde_names && is_serde_skipped(&field.attrs)
+                                    {
                                         None
                                     } else {
-                                        let field_name_str: String = field_name.to_string();
+                                        let field_name_str: String = serde_names
+                                            .then(|| {
+                                                resolve_serde_name(
+                                                    &field.attrs,
+                                                    &field_name.to_string(),
+                                                    variant_rename_all.as_deref(),
+                                                    false,
+                                                )
+                                            })
+                                            .flatten()
+                                            .unwrap_or_else(|| field_name.to_string());
                                         Some(quote! {
                                             .field(#field_name_str, #field_name)
                                         })
@@ -1539,7 +2853,8 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
                                             break;
                                         }
                                     }
-                                    if should_skip {
+                                    if should_skip || serde_names && is_serde_skipped(&field.attrs)
+                                    {
                                         None
                                     } else {
                                         let field_name: Ident = format_ident!("field_{}", i);
@@ -1584,6 +2899,26 @@ pub(crate) fn inner_custom_debug(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Checks if a field carries a `skip` flag for the given function type.
+///
+/// # Arguments
+///
+/// - `&Field` - The field structure to analyze for skip attribute.
+/// - `FuncType` - The function type whose attribute must contain `skip`.
+///
+/// # Returns
+///
+/// - `bool` - True if the field should be skipped, false otherwise.
+pub(crate) fn should_skip_field(field: &Field, func_type: FuncType) -> bool {
+    for attr in &field.attrs {
+        let config: Config = analyze_attributes(attr.to_token_stream());
+        if config.func_type == func_type && config.skip_flags.contains(&func_type) {
+            return true;
+        }
+    }
+    false
+}
+
 /// Checks if a field should be skipped for New constructor generation.
 ///
 /// # Arguments
@@ -1605,6 +2940,89 @@ fn should_skip_field_for_new(field: &Field) -> bool {
     should_skip
 }
 
+/// Checks if a field carries a `flatten` flag in the attributes named `attr_name`.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to analyze.
+/// - `&str` - The attribute name, such as `new`, `builder` or `get`.
+///
+/// # Returns
+///
+/// - `bool` - True if the field is flattened, false otherwise.
+pub(crate) fn is_flatten_field(field: &Field, attr_name: &str) -> bool {
+    parse_attribute_flags(&field.attrs, attr_name)
+        .iter()
+        .any(|flag| flag == FLATTEN)
+}
+
+/// Parses the entries declared with `flatten(name: Type, ...)` in the attributes named `attr_name`.
+///
+/// For `#[new(flatten(...))]` the entries are the constructor parameters of the flattened
+/// field's type; for `#[get(flatten(...))]` they are the getters forwarded to it.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to analyze.
+/// - `&str` - The attribute name, `new` or `get`.
+/// - `&str` - The error reported for a bare `flatten` flag without entries.
+///
+/// # Returns
+///
+/// - `Option<Vec<FlattenParam>>` - The declared entries, or None if the field is not flattened.
+fn parse_flatten_params(
+    field: &Field,
+    attr_name: &str,
+    requires_params: &str,
+) -> Option<Vec<FlattenParam>> {
+    if is_flatten_field(field, attr_name) {
+        panic!("{}", requires_params);
+    }
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(attr_name))
+    {
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        while let Some(token) = tokens_iter.next() {
+            if let TokenTree2::Ident(ident) = &token
+                && ident == FLATTEN
+                && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                && group.delimiter() == Delimiter::Parenthesis
+            {
+                let params: Punctuated<FlattenParam, Token![,]> =
+                    Punctuated::<FlattenParam, Token![,]>::parse_terminated
+                        .parse2(group.stream())
+                        .unwrap_or_else(|error| panic!("{}: {}", INVALID_FLATTEN_PARAMS, error));
+                return Some(params.into_iter().collect());
+            }
+        }
+    }
+    None
+}
+
+/// Generates the default value of a field from its `#[default(expr)]` attribute.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to generate the default value for.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The declared expression, or `Default::default()` if none is declared.
+pub(crate) fn generate_field_default(field: &Field) -> TokenStream2 {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(DEFAULT))
+        .find_map(|attr| attr.meta.require_list().ok())
+        .map(|meta_list| meta_list.tokens.clone())
+        .unwrap_or_else(|| quote! { std::default::Default::default() })
+}
+
 /// Generates a constructor function for named struct fields, excluding skipped fields.
 ///
 /// # Arguments
@@ -1642,6 +3060,21 @@ fn analyze_tuple_field_for_new(field: &Field, index: usize) -> Option<(Ident, Ty
     Some((param_name, field_type.clone()))
 }
 
+/// Generates the call building a flattened field from its inlined constructor parameters.
+///
+/// # Arguments
+///
+/// - `&Type` - The type of the flattened field.
+/// - `&[FlattenParam]` - The constructor parameters declared for the field.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated `<Type>::new(..)` call.
+fn generate_flatten_new_call(field_type: &Type, flatten_params: &[FlattenParam]) -> TokenStream2 {
+    let param_names: Vec<&Ident> = flatten_params.iter().map(|param| &param.name).collect();
+    quote! { <#field_type>::new(#(#param_names),*) }
+}
+
 /// Generates a constructor function for a struct with the specified visibility.
 ///
 /// # Arguments
@@ -1692,11 +3125,44 @@ pub(crate) fn inner_new_constructor(input: &DeriveInput, visibility: Visibility)
         },
         _ => Vec::new(),
     };
-    let params: Vec<TokenStream2> = fields_info
+    let flattened: Vec<Option<Vec<FlattenParam>>> = match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .filter(|field| !should_skip_field_for_new(field))
+            .map(|field| parse_flatten_params(field, NEW, NEW_FLATTEN_REQUIRES_PARAMS))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let param_decls: Vec<(&Ident, &Type)> = fields_info
         .iter()
-        .map(|(field_name, field_type)| {
-            quote! { #field_name: #field_type }
-        })
+        .zip(&flattened)
+        .flat_map(
+            |((field_name, field_type), flatten_params)| match flatten_params {
+                Some(flatten_params) => flatten_params
+                    .iter()
+                    .map(|FlattenParam { name, ty }| (name, ty))
+                    .collect(),
+                None => vec![(field_name, field_type)],
+            },
+        )
+        .collect();
+    for (index, (param_name, _)) in param_decls.iter().enumerate() {
+        if param_decls[..index]
+            .iter()
+            .any(|(previous_name, _)| previous_name == param_name)
+        {
+            return syn::Error::new_spanned(
+                param_name,
+                format!("{}: `{}`", DUPLICATE_NEW_PARAM, param_name),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+    let params: Vec<TokenStream2> = param_decls
+        .iter()
+        .map(|(param_name, param_type)| quote! { #param_name: #param_type })
         .collect();
     let constructor_fields: TokenStream2 = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
@@ -1706,10 +3172,17 @@ pub(crate) fn inner_new_constructor(input: &DeriveInput, visibility: Visibility)
                     .iter()
                     .filter_map(|field| {
                         let original_name: &Ident = field.ident.as_ref()?;
-                        if !should_skip_field_for_new(field) {
-                            Some(quote! { #original_name: #original_name })
+                        if should_skip_field_for_new(field) {
+                            let default_value: TokenStream2 = generate_field_default(field);
+                            Some(quote! { #original_name: #default_value })
+                        } else if let Some(flatten_params) =
+                            parse_flatten_params(field, NEW, NEW_FLATTEN_REQUIRES_PARAMS)
+                        {
+                            let value: TokenStream2 =
+                                generate_flatten_new_call(&field.ty, &flatten_params);
+                            Some(quote! { #original_name: #value })
                         } else {
-                            Some(quote! { #original_name: Default::default() })
+                            Some(quote! { #original_name: #original_name })
                         }
                     })
                     .collect();
@@ -1727,12 +3200,18 @@ pub(crate) fn inner_new_constructor(input: &DeriveInput, visibility: Visibility)
                                 .iter()
                                 .find(|(idx, _)| *idx == field_index)
                             {
-                                quote! { #param_name }
+                                if let Some(flatten_params) =
+                                    parse_flatten_params(field, NEW, NEW_FLATTEN_REQUIRES_PARAMS)
+                                {
+                                    generate_flatten_new_call(&field.ty, &flatten_params)
+                                } else {
+                                    quote! { #param_name }
+                                }
                             } else {
-                                quote! { Default::default() }
+                                generate_field_default(field)
                             }
                         } else {
-                            quote! { Default::default() }
+                            generate_field_default(field)
                         }
                     })
                     .collect();
@@ -0,0 +1,10 @@
+/// Error message indicating that #[derive(GetterTrait)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_GETTER_TRAIT_DERIVE: &str =
+    "#[derive(GetterTrait)] is only supported for structs with named fields.";
+
+/// Error message indicating that the trait name of #[getter_trait(name = "...")] cannot be used.
+pub(crate) const INVALID_GETTER_TRAIT_NAME: &str =
+    "#[getter_trait(name = \"...\")] expects a trait name, or a trait path together with impl_only";
+
+/// Suffix of the trait name used when #[getter_trait(name = "...")] is absent.
+pub(crate) const DEFAULT_GETTER_TRAIT_SUFFIX: &str = "Getters";
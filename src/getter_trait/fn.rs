@@ -0,0 +1,63 @@
+use crate::*;
+
+/// Generates a getter trait and its implementation for a struct.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated trait declaration and implementation.
+pub(crate) fn inner_getter_trait(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let trait_path: Path = match parse_attribute_string(&input.attrs, GETTER_TRAIT, NAME) {
+        Err(error) => return error.to_compile_error().into(),
+        Ok(Some(trait_name)) => syn::parse_str::<Path>(&trait_name)
+            .unwrap_or_else(|error| panic!("{}: {}", INVALID_GETTER_TRAIT_NAME, error)),
+        Ok(None) => format_ident!("{}{}", name, DEFAULT_GETTER_TRAIT_SUFFIX).into(),
+    };
+    let impl_only: bool = parse_attribute_flags(&input.attrs, GETTER_TRAIT)
+        .iter()
+        .any(|flag| flag == IMPL_ONLY);
+    let atomic_ordering: ContainerAtomicOrdering = parse_container_atomic_ordering(&input.attrs);
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields.named.iter().collect(),
+            _ => panic!("{}", UNSUPPORTED_GETTER_TRAIT_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_GETTER_TRAIT_DERIVE),
+    };
+    let getters: Vec<TokenStream2> = fields
+        .iter()
+        .filter_map(|field| generate_named_getter(field, &atomic_ordering))
+        .collect();
+    let (declaration, trait_ref): (TokenStream2, TokenStream2) = if impl_only {
+        (quote! {}, trait_path.to_token_stream())
+    } else {
+        let trait_name: &Ident = trait_path.get_ident().expect(INVALID_GETTER_TRAIT_NAME);
+        let signatures: Vec<TokenStream2> = fields
+            .iter()
+            .filter_map(|field| generate_named_getter_signature(field, &atomic_ordering))
+            .collect();
+        (
+            quote! {
+                #vis trait #trait_name #impl_generics #where_clause {
+                    #(#signatures)*
+                }
+            },
+            quote! { #trait_name #ty_generics },
+        )
+    };
+    let expanded: TokenStream2 = quote! {
+        #declaration
+
+        impl #impl_generics #trait_ref for #name #ty_generics #where_clause {
+            #(#getters)*
+        }
+    };
+    expanded.into()
+}
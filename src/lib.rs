@@ -6,22 +6,51 @@
 //! Supports structs, enums, generics and lifetimes.
 
 mod config;
+mod data;
+mod delegate;
+mod diff;
+mod field_access;
+mod field_names;
+mod from_env;
 mod func;
 mod generate;
+mod getter_trait;
+mod map_from;
+mod merge;
+mod newtype;
 mod parse;
+mod patch;
+mod properties;
+mod smart_default;
+mod typed_builder;
+mod value;
+mod view;
 mod visibility;
+mod visit_fields;
 
-use {config::*, func::*, generate::*, parse::*, visibility::*};
+use {
+    config::*, data::*, delegate::*, diff::*, field_access::*, field_names::*, from_env::*,
+    func::*, generate::*, getter_trait::*, map_from::*, merge::*, newtype::*, parse::*, patch::*,
+    properties::*, smart_default::*, typed_builder::*, value::*, view::*, visibility::*,
+    visit_fields::*,
+};
 
 use {
     proc_macro::TokenStream,
     proc_macro2::{
-        Delimiter, TokenStream as TokenStream2, TokenTree as TokenTree2, token_stream::IntoIter,
+        Delimiter, Span, TokenStream as TokenStream2, TokenTree as TokenTree2,
+        token_stream::IntoIter,
     },
-    quote::{ToTokens, format_ident, quote},
+    quote::{ToTokens, format_ident, quote, quote_spanned},
     syn::{
-        Data, DeriveInput, Field, Fields, GenericArgument, GenericParam, Generics, Ident, Index,
-        Lifetime, PathArguments, Type, TypeParam, Variant, WhereClause, parse_macro_input, parse2,
+        Attribute, Data, DeriveInput, Expr, ExprUnary, Field, Fields, GenericArgument,
+        GenericParam, Generics, Ident, Index, Lifetime, LifetimeParam, Lit, LitStr, Member,
+        MetaList, Path, PathArguments, PathSegment, Token, Type, TypeParam, TypeParamBound, UnOp,
+        Variant, WhereClause, parenthesized,
+        parse::{Parse, ParseBuffer, ParseStream, Parser},
+        parse_macro_input, parse_quote, parse2,
+        punctuated::Punctuated,
+        spanned::Spanned,
     },
 };
 
@@ -44,8 +73,16 @@ use std::{
 /// - `#[get(pub, clone)]` - Generates a public getter that returns a cloned value (`T`)
 /// - `#[get(pub, copy)]` - Generates a public getter that returns a copy of the field value (`self.field`) for Copy types
 /// - `#[get(pub, deref)]` - Generates a public getter that returns a dereferenced value (`*field`) with enhanced match control for Option/Result types
+/// - `#[get(pub, lazy = Self::compute)]` - Generates a public getter on a `OnceCell<T>` or `OnceLock<T>` field that returns `&T`, computing it once with `Self::compute(&self)`
+/// - `#[get(pub, atomic(Acquire))]` - Generates a public getter on a `std::sync::atomic` field that loads the value (`T`) with the given ordering; `atomic` alone uses `SeqCst`
+/// - `#[get(atomic(Acquire))]` - On the struct itself, loads every atomic field with the given ordering unless the field specifies its own
+/// - `#[get(pub, computed(name: T = expr))]` - On the struct itself, generates a public `get_name(&self) -> T` evaluating `expr` with `self` in scope; several comma-separated declarations are allowed
 /// - `#[get(pub(crate))]` - Generates a crate-visible getter
 /// - `#[get(private)]` - Generates a private getter
+/// - `#[get(pub, flatten(name: T, ..))]` - Generates a public `get_name(&self) -> T` per entry,
+///   forwarding to the `get_name` getter of the field's type; `T` is that getter's return type
+/// - `#[get(pub, into_owned)]` - Generates a public getter on a `Cow<'a, B>` field that returns the owned value (`B::Owned`)
+/// - `#[get(pub, unwrap)]` - Generates a public getter on a `Cell`, `RefCell`, `Mutex` or `RwLock` field that returns the inner value or guard
 ///
 /// # Return Type Behavior
 /// - `reference` - Returns `&T` - a reference to the field value
@@ -58,8 +95,23 @@ use std::{
 ///   - `Rc<T>` → `T` by cloning the inner value
 ///   - `Arc<T>` → `T` by cloning the inner value
 ///   - Other types → `T` by dereferencing
+/// - `into_owned` - Returns `B::Owned` for `Cow<'a, B>` fields, such as `String` for `Cow<'a, str>`
 /// - Default behavior: Returns `&T` for non-Option/Result types, `T` for Option/Result types
 ///
+/// # Copy-on-Write
+/// With the default reference behavior, `Cow<'a, B>` fields return `&B` instead of `&Cow<'a, B>`,
+/// such as `&str` for `Cow<'a, str>` and `&[T]` for `Cow<'a, [T]>`.
+///
+/// # Interior Mutability
+/// Wrapper fields return `&Wrapper<T>` by default. With `#[get(unwrap)]` they are unwrapped instead:
+/// - `Cell<T>` → `T` via `Cell::get` (requires `T: Copy`)
+/// - `RefCell<T>` → `Ref<'_, T>` via `RefCell::borrow`
+/// - `Mutex<T>` → `MutexGuard<'_, T>`, plus `try_get_x()` returning the `LockResult` when the lock may be poisoned
+/// - `RwLock<T>` → `RwLockReadGuard<'_, T>`, plus `try_get_x()` returning the `LockResult`
+///
+/// Only the standard library types are supported; a qualified path must start with `std` or `core`,
+/// and locks from other crates such as `parking_lot` fail compilation with an explanatory error.
+///
 /// # Default Behavior Details
 /// - **Non-Option/Result types**: Returns `&T` (reference to field)
 /// - **Option/Result types**: Returns `T` (cloned value) to avoid exposing internal references
@@ -212,6 +264,150 @@ use std::{
 /// assert_eq!(arc_value, vec![1, 2, 3]);
 /// ```
 ///
+/// ## Lazy Cached Values
+///
+/// Use `OnceCell<T>` for single-threaded types and `OnceLock<T>` when the value must be
+/// initialized safely from several threads.
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::cell::{Cell, OnceCell};
+/// use std::sync::OnceLock;
+///
+/// #[derive(Getter)]
+/// struct Document {
+///     #[get(pub)]
+///     body: String,
+///     #[get(pub, lazy = Self::compute_hash)]
+///     hash: OnceCell<u64>,
+///     #[get(pub, lazy = Self::compute_words)]
+///     words: OnceLock<usize>,
+///     #[get(skip)]
+///     computations: Cell<u32>,
+/// }
+///
+/// impl Document {
+///     fn compute_hash(&self) -> u64 {
+///         self.computations.set(self.computations.get() + 1);
+///         self.body.bytes().map(u64::from).sum()
+///     }
+///
+///     fn compute_words(&self) -> usize {
+///         self.body.split_whitespace().count()
+///     }
+/// }
+///
+/// let document = Document {
+///     body: "hello world".to_string(),
+///     hash: OnceCell::new(),
+///     words: OnceLock::new(),
+///     computations: Cell::new(0),
+/// };
+/// let hash: &u64 = document.get_hash();
+/// assert_eq!(*hash, 1116);
+/// assert_eq!(*document.get_hash(), 1116);
+/// assert_eq!(document.computations.get(), 1);
+/// assert_eq!(*document.get_words(), 2);
+/// ```
+///
+/// ## Interior Mutability
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::cell::{Cell, RefCell};
+/// use std::sync::{Mutex, RwLock};
+///
+/// #[derive(Getter)]
+/// struct Shared {
+///     #[get(pub, unwrap)]
+///     hits: Cell<u32>,
+///     #[get(pub, unwrap)]
+///     names: RefCell<Vec<String>>,
+///     #[get(pub, unwrap)]
+///     total: Mutex<u64>,
+///     #[get(pub, unwrap)]
+///     label: RwLock<String>,
+///     #[get(pub)]
+///     note: Cell<String>,
+/// }
+///
+/// let shared = Shared {
+///     hits: Cell::new(3),
+///     names: RefCell::new(vec!["a".to_string()]),
+///     total: Mutex::new(10),
+///     label: RwLock::new("ready".to_string()),
+///     note: Cell::new("wrapped".to_string()),
+/// };
+/// assert_eq!(shared.get_hits(), 3);
+/// assert_eq!(shared.get_names().len(), 1);
+/// *shared.get_total() += 5;
+/// assert_eq!(*shared.try_get_total().unwrap(), 15);
+/// assert_eq!(shared.get_label().as_str(), "ready");
+/// assert!(shared.try_get_label().is_ok());
+/// let note: &Cell<String> = shared.get_note();
+/// assert_eq!(note.take(), "wrapped");
+/// ```
+///
+/// ## Atomic Fields
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize};
+///
+/// #[derive(Getter)]
+/// #[get(atomic(Relaxed))]
+/// struct Metrics {
+///     #[get(pub)]
+///     requests: AtomicU64,
+///     #[get(pub, atomic(Acquire))]
+///     ready: AtomicBool,
+///     #[get(pub)]
+///     name: String,
+///     #[get(pub, atomic)]
+///     errors: AtomicUsize,
+/// }
+///
+/// let metrics = Metrics {
+///     requests: AtomicU64::new(7),
+///     ready: AtomicBool::new(true),
+///     name: "api".to_string(),
+///     errors: AtomicUsize::new(0),
+/// };
+/// let requests: u64 = metrics.get_requests();
+/// assert_eq!(requests, 7);
+/// assert!(metrics.get_ready());
+/// assert_eq!(metrics.get_name(), "api");
+/// assert_eq!(metrics.get_errors(), 0);
+/// ```
+///
+/// ## Computed Getters
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Getter)]
+/// #[get(pub, computed(
+///     full_name: String = format!("{} {}", self.first, self.last),
+///     initials: (char, char) = (self.first.chars().next().unwrap(), self.last.chars().next().unwrap())
+/// ))]
+/// #[get(pub(crate), computed(name_len: usize = self.first.len() + self.last.len()))]
+/// struct Person {
+///     #[get(pub)]
+///     first: String,
+///     #[get(pub)]
+///     last: String,
+/// }
+///
+/// let person = Person {
+///     first: "Ada".to_string(),
+///     last: "Lovelace".to_string(),
+/// };
+/// assert_eq!(person.get_full_name(), "Ada Lovelace");
+/// assert_eq!(person.get_initials(), ('A', 'L'));
+/// assert_eq!(person.get_name_len(), 11);
+/// assert_eq!(person.get_first(), "Ada");
+/// ```
+///
 /// ## Generics and Lifetimes
 ///
 /// ```rust
@@ -235,6 +431,70 @@ use std::{
 /// assert_eq!(*value_ref, 42);
 /// assert_eq!(owned_clone, 42);
 /// ```
+///
+/// ## Flattened Fields
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Getter)]
+/// struct Metadata {
+///     author: String,
+///     #[get(type(copy))]
+///     version: u32,
+/// }
+///
+/// #[derive(Getter)]
+/// struct Document {
+///     title: String,
+///     #[get(flatten(author: &String, version: u32))]
+///     metadata: Metadata,
+/// }
+///
+/// let document = Document {
+///     title: "Guide".to_string(),
+///     metadata: Metadata {
+///         author: "alice".to_string(),
+///         version: 2,
+///     },
+/// };
+/// assert_eq!(document.get_title(), "Guide");
+/// assert_eq!(document.get_author(), "alice");
+/// assert_eq!(document.get_version(), 2);
+/// assert_eq!(document.get_metadata().get_author(), "alice");
+/// ```
+///
+/// ## Copy-on-Write Fields
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::borrow::Cow;
+///
+/// #[derive(Getter, Setter)]
+/// struct Label<'a> {
+///     text: Cow<'a, str>,
+///     #[get(pub, into_owned)]
+///     owned_text: Cow<'a, str>,
+///     bytes: Cow<'a, [u8]>,
+/// }
+///
+/// let mut label: Label = Label {
+///     text: Cow::Borrowed("borrowed"),
+///     owned_text: Cow::Borrowed("owned"),
+///     bytes: Cow::Borrowed(&[1, 2]),
+/// };
+/// let text: &str = label.get_text();
+/// assert_eq!(text, "borrowed");
+/// let owned_text: String = label.get_owned_text();
+/// assert_eq!(owned_text, "owned");
+/// let bytes: &[u8] = label.get_bytes();
+/// assert_eq!(bytes, &[1, 2]);
+///
+/// label.set_text("literal").set_text(String::from("owned string"));
+/// label.set_bytes(vec![3]);
+/// assert_eq!(label.get_text(), "owned string");
+/// assert!(matches!(label.bytes, Cow::Owned(_)));
+/// ```
 #[proc_macro_derive(Getter, attributes(get))]
 pub fn getter(input: TokenStream) -> TokenStream {
     inner_lombok_data(input, true, false, false)
@@ -250,6 +510,39 @@ pub fn getter(input: TokenStream) -> TokenStream {
 /// - `#[get_mut(pub(crate))]` - Generates a crate-visible mutable getter
 /// - `#[get_mut(pub(super))]` - Generates a mutable getter visible to parent module
 /// - `#[get_mut(private)]` - Generates a private mutable getter
+/// - `#[get_mut(split)]` - On the struct, also generates `split_mut(&mut self) -> {Struct}Mut<'_>`,
+///   a struct holding a `&mut` reference to every field without `#[get_mut(skip)]`
+///
+/// # Split Borrows
+///
+/// Each `get_mut_*` call borrows the whole struct, so two of them cannot be held at once.
+/// `split_mut` borrows every field separately instead. The returned struct has the same
+/// visibility as the original one and ends with a private `_marker` field, so destructuring
+/// it requires `..`.
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(GetterMut)]
+/// #[get_mut(split)]
+/// struct Inventory {
+///     items: Vec<String>,
+///     total: usize,
+///     #[get_mut(skip)]
+///     locked: bool,
+/// }
+///
+/// let mut inventory: Inventory = Inventory {
+///     items: Vec::new(),
+///     total: 0,
+///     locked: false,
+/// };
+/// let InventoryMut { items, total, .. } = inventory.split_mut();
+/// items.push("apple".to_string());
+/// *total += items.len();
+/// assert_eq!(inventory.total, 1);
+/// assert!(!inventory.locked);
+/// ```
 ///
 /// # Example
 ///
@@ -293,12 +586,25 @@ pub fn getter_mut(input: TokenStream) -> TokenStream {
 /// - `#[set(pub, type(AsRef<str>))]` - Generates a setter with custom parameter type conversion
 /// - `#[set(pub, Into)]` - Generates a setter using `impl Into<T>` trait bound
 /// - `#[set(pub, type(AsRef<[u8]>))]` - Generates a setter with `impl AsRef<[u8]>` parameter type
+/// - `#[set(pub, unwrap)]` - Generates a public `set_x(&self, val: T)` on a `Cell<T>`, `RefCell<T>`, `Mutex<T>` or `RwLock<T>` field
+///
+/// - `#[set(pub, atomic(Release))]` - Generates a public `set_x(&self, val: T)` on a `std::sync::atomic` field that stores with the given ordering; `atomic` alone uses `SeqCst`
+/// - `#[set(pub, atomic(Relaxed), fetch_add)]` - Also generates `fetch_add_x(&self, val: T) -> T` and `increment_x(&self) -> T` on atomic integer fields, both returning the previous value
+/// - `#[set(atomic(Release))]` - On the struct itself, stores into every atomic field with the given ordering unless the field specifies its own
+///
+/// # Interior Mutability
+/// With `#[set(unwrap)]`, fields wrapped in the standard `Cell<T>`, `RefCell<T>`, `Mutex<T>` or `RwLock<T>`
+/// get a setter taking `&self` and a value of the inner type `T`, so they can be updated through a shared reference.
+/// `Mutex<T>` and `RwLock<T>` fields also get a `try_set_x` variant that returns the
+/// `PoisonError` instead of panicking when the lock is poisoned.
 ///
 /// # Parameter Type Conversion
 /// Setters support flexible parameter type conversion through trait bounds:
 /// - `type(AsRef<T>)` - Accepts any type implementing `AsRef<T>` and converts using `.as_ref().to_owned()`
 /// - `type(Into<T>)` - Accepts any type implementing `Into<T>` and converts using `.into()`
 /// - `type(CustomTrait<T>)` - Accepts any type implementing the specified custom trait bound
+/// - `Cow<'a, B>` fields without a `type(...)` option accept `impl Into<Cow<'a, B>>`, so both
+///   borrowed and owned values can be passed
 ///
 /// # Examples
 ///
@@ -359,6 +665,65 @@ pub fn getter_mut(input: TokenStream) -> TokenStream {
 /// assert_eq!(conversion.data, vec![4, 5, 6]);
 /// ```
 ///
+/// ## Interior Mutability
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::cell::{Cell, RefCell};
+/// use std::sync::{Mutex, RwLock};
+///
+/// #[derive(Setter)]
+/// struct Shared {
+///     #[set(pub, unwrap)]
+///     hits: Cell<u32>,
+///     #[set(pub, unwrap, type(AsRef<str>))]
+///     name: RefCell<String>,
+///     #[set(pub, unwrap)]
+///     total: Mutex<u64>,
+///     #[set(pub, unwrap)]
+///     label: RwLock<String>,
+/// }
+///
+/// let shared = Shared {
+///     hits: Cell::new(0),
+///     name: RefCell::new(String::new()),
+///     total: Mutex::new(0),
+///     label: RwLock::new(String::new()),
+/// };
+/// shared.set_hits(1).set_name("ada").set_total(7);
+/// assert!(shared.try_set_label("ready".to_string()).is_ok());
+/// assert_eq!(shared.hits.get(), 1);
+/// assert_eq!(*shared.name.borrow(), "ada");
+/// assert_eq!(*shared.total.lock().unwrap(), 7);
+/// assert_eq!(*shared.label.read().unwrap(), "ready");
+/// ```
+///
+/// ## Atomic Fields
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+///
+/// #[derive(Setter)]
+/// #[set(atomic(Release))]
+/// struct Metrics {
+///     #[set(pub, atomic(Relaxed), fetch_add)]
+///     requests: AtomicU64,
+///     #[set(pub)]
+///     ready: AtomicBool,
+/// }
+///
+/// let metrics = Metrics {
+///     requests: AtomicU64::new(0),
+///     ready: AtomicBool::new(false),
+/// };
+/// metrics.set_requests(10).set_ready(true);
+/// assert_eq!(metrics.fetch_add_requests(5), 10);
+/// assert_eq!(metrics.increment_requests(), 15);
+/// assert_eq!(metrics.requests.load(Ordering::Relaxed), 16);
+/// assert!(metrics.ready.load(Ordering::Acquire));
+/// ```
+///
 /// ## Tuple Structs
 ///
 /// ```rust
@@ -387,10 +752,25 @@ pub fn setter(input: TokenStream) -> TokenStream {
 /// for struct and enum fields, providing comprehensive data manipulation capabilities with
 /// configurable visibility and behavior options.
 ///
+/// The `#[data(...)]` container attribute can additionally emit what `New`, `CustomDebug`,
+/// `DisplayDebug` and equality or hashing derives produce, so a single derive covers the whole type.
+///
+/// `#[new(...)]`, `#[debug(...)]` and `#[default(...)]` attributes are accepted by `Data` so the
+/// options above can read them, but they must configure something: without the matching option,
+/// the struct fails to compile unless it gets a `new` function, a `Debug` implementation or,
+/// for `#[default(...)]` on a field without `#[new(...)]`, a `Default` implementation elsewhere,
+/// such as from `#[derive(New)]`, `#[derive(CustomDebug)]` or `#[derive(SmartDefault)]`.
+///
 /// # Supported Attributes
 /// - `#[get(...)]` - Controls getter generation (supports `reference`, `clone`, `copy`, `deref` options)
 /// - `#[get_mut(...)]` - Controls mutable getter generation
 /// - `#[set(...)]` - Controls setter generation (supports parameter type conversion with `type(AsRef<T>)`, `Into`, etc.)
+/// - `#[data(new, debug, display, eq, hash)]` - Container attribute enabling any combination of:
+///   - `new` - A constructor, configured with `#[new(...)]` exactly as for `New`
+///   - `debug` - A `Debug` implementation honoring `#[debug(skip)]`, as for `CustomDebug`
+///   - `display` - A `Display` implementation using the debug format, as for `DisplayDebug`
+///   - `eq` - `PartialEq` and `Eq` implementations comparing every field
+///   - `hash` - A `Hash` implementation hashing every field, consistent with `eq`
 ///
 /// # Visibility Control
 /// Each attribute supports the same visibility options:
@@ -499,7 +879,49 @@ pub fn setter(input: TokenStream) -> TokenStream {
 /// let updated_y_coordinate: f64 = point.get_1();
 /// assert_eq!(updated_y_coordinate, 3.0);
 /// ```
-#[proc_macro_derive(Data, attributes(get, get_mut, set))]
+///
+/// ## All-in-One with Container Options
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Clone, Data)]
+/// #[data(new, debug, display, eq, hash)]
+/// struct Account {
+///     #[get(pub)]
+///     #[set(pub)]
+///     name: String,
+///     #[debug(skip)]
+///     password: String,
+///     #[new(skip)]
+///     logins: u32,
+/// }
+///
+/// let mut account = Account::new("alice".to_string(), "secret".to_string());
+/// assert_eq!(account.get_name(), "alice");
+/// assert_eq!(*account.get_logins(), 0);
+/// assert_eq!(format!("{account:?}"), "Account { name: \"alice\", logins: 0 }");
+/// assert_eq!(account.to_string(), "Account { name: \"alice\", logins: 0 }");
+/// let snapshot = account.clone();
+/// assert_eq!(account, snapshot);
+/// account.set_name("bob".to_string());
+/// assert_ne!(account, snapshot);
+/// let accounts: std::collections::HashSet<Account> = [account, snapshot].into_iter().collect();
+/// assert_eq!(accounts.len(), 2);
+/// ```
+///
+/// ## Unused Companion Attributes
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(Data)]
+/// struct Account {
+///     #[new(skip)]
+///     logins: u32,
+/// }
+/// ```
+#[proc_macro_derive(Data, attributes(get, get_mut, set, data, new, debug, default, lombok))]
 pub fn data(input: TokenStream) -> TokenStream {
     let mut result: TokenStream2 = TokenStream2::new();
     let lombok_data: TokenStream = inner_lombok_data(input.clone(), true, true, true);
@@ -509,6 +931,7 @@ pub fn data(input: TokenStream) -> TokenStream {
             .parse::<TokenStream2>()
             .unwrap_or_default(),
     );
+    result.extend(TokenStream2::from(inner_data_options(input)));
     result.into()
 }
 
@@ -559,6 +982,9 @@ pub fn display_debug_format(input: TokenStream) -> TokenStream {
 ///
 /// # Supported Attributes
 /// - `#[debug(skip)]` - Excludes the field from the debug output
+/// - `#[lombok(serde_names)]` - On the type, labels fields and variants with their `#[serde(rename = "...")]`
+///   wire names and excludes fields marked `#[serde(skip)]` or `#[serde(skip_serializing)]`;
+///   `#[serde(rename_all = "...")]` rules and `rename(serialize = "...")` names are applied as serde does
 ///
 /// # Examples
 ///
@@ -605,6 +1031,38 @@ pub fn display_debug_format(input: TokenStream) -> TokenStream {
 /// assert_eq!(format!("{:?}", error), expected_error);
 /// ```
 ///
+/// ## Serde Wire Names
+/// ```rust
+/// use lombok_macros::*;
+/// use serde::Serialize;
+///
+/// #[derive(CustomDebug, Serialize)]
+/// #[lombok(serde_names)]
+/// struct Session {
+///     #[serde(rename = "userId")]
+///     user_id: u64,
+///     #[serde(skip)]
+///     token: String,
+/// }
+///
+/// let session = Session { user_id: 7, token: "secret".to_string() };
+/// assert_eq!(format!("{:?}", session), "Session { userId: 7 }");
+///
+/// #[derive(CustomDebug, Serialize)]
+/// #[lombok(serde_names)]
+/// #[serde(rename_all = "snake_case")]
+/// enum Event {
+///     #[serde(rename_all = "camelCase")]
+///     UserLoggedIn { user_id: u64 },
+///     #[serde(rename(serialize = "closed"))]
+///     SessionClosed,
+/// }
+///
+/// let event = Event::UserLoggedIn { user_id: 7 };
+/// assert_eq!(format!("{:?}", event), "user_logged_in { userId: 7 }");
+/// assert_eq!(format!("{:?}", Event::SessionClosed), "closed");
+/// ```
+///
 /// # Arguments
 /// - `input` - The input token stream representing the Rust item (struct, enum, etc.)
 ///   for which the Debug implementation will be generated.
@@ -612,7 +1070,7 @@ pub fn display_debug_format(input: TokenStream) -> TokenStream {
 /// # Returns
 /// - `TokenStream` - The generated `std::fmt::Debug` implementation for the type
 ///   that respects the `#[debug(skip)]` attribute.
-#[proc_macro_derive(CustomDebug, attributes(debug))]
+#[proc_macro_derive(CustomDebug, attributes(debug, lombok))]
 pub fn custom_debug(input: TokenStream) -> TokenStream {
     inner_custom_debug(input)
 }
@@ -629,11 +1087,23 @@ pub fn custom_debug(input: TokenStream) -> TokenStream {
 /// - `#[new(pub(crate))]` - Generates a crate-visible constructor  
 /// - `#[new(pub(super))]` - Generates a constructor visible to parent module  
 /// - `#[new(private)]` - Generates a private constructor
+/// - `#[default(expr)]` - Initializes a `#[new(skip)]` field with `expr`, shared with `SmartDefault`
+/// - `#[new(flatten(name: Type, ..))]` - Inlines the listed parameters into the constructor in place
+///   of the field, which is built with `<T>::new(name, ..)`; the list must match the parameters of
+///   the field type's `new`
+///
+/// # Flattened Fields
+/// A derive only sees the struct it is applied to, not the fields of `T`, so a bare `#[new(flatten)]`
+/// cannot discover the parameters of `T::new` and is rejected; they are listed in `flatten(...)`.
+/// The list is checked against `T::new` when the generated call compiles: a changed parameter type
+/// or count is a compile error, but swapping two parameters of the same type is not, so keep the
+/// order in sync with `T::new`. A listed name that is already a constructor parameter is a compile
+/// error pointing at the duplicate.
 ///
 /// # Default Behavior
 /// - The generated constructor is `pub` by default
 /// - All fields are included in the constructor unless marked with `#[new(skip)]`
-/// - Skipped fields are initialized using `Default::default()`
+/// - Skipped fields are initialized using their `#[default(expr)]` or `Default::default()`
 ///
 /// # Examples
 ///
@@ -715,14 +1185,1421 @@ pub fn custom_debug(input: TokenStream) -> TokenStream {
 /// assert_eq!(container.count, 0);
 /// ```
 ///
+/// ## Default Expressions
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(New)]
+/// struct Connection {
+///     host: String,
+///     #[new(skip)]
+///     #[default(5432)]
+///     port: u16,
+///     #[new(skip)]
+///     retries: u8,
+/// }
+///
+/// let connection = Connection::new("localhost".to_string());
+/// assert_eq!(connection.host, "localhost");
+/// assert_eq!(connection.port, 5432);
+/// assert_eq!(connection.retries, 0);
+/// ```
+///
+/// ## Flattened Fields
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(New)]
+/// struct Metadata {
+///     author: String,
+///     version: u32,
+/// }
+///
+/// #[derive(New)]
+/// struct Document {
+///     title: String,
+///     #[new(flatten(author: String, version: u32))]
+///     metadata: Metadata,
+/// }
+///
+/// let document = Document::new("Guide".to_string(), "alice".to_string(), 2);
+/// assert_eq!(document.title, "Guide");
+/// assert_eq!(document.metadata.author, "alice");
+/// assert_eq!(document.metadata.version, 2);
+/// ```
+///
+/// A flattened parameter cannot reuse the name of another constructor parameter:
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(New)]
+/// struct Metadata {
+///     version: u32,
+/// }
+///
+/// #[derive(New)]
+/// struct Document {
+///     version: u32,
+///     #[new(flatten(version: u32))]
+///     metadata: Metadata,
+/// }
+/// ```
+///
 /// # Arguments
 /// - `input` - The input token stream representing the struct for which to generate the constructor.
 ///
 /// # Returns
 /// - `TokenStream` - The generated constructor implementation.
-#[proc_macro_derive(New, attributes(new))]
+#[proc_macro_derive(New, attributes(new, default))]
 pub fn new(input: TokenStream) -> TokenStream {
     let derive_input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let visibility: Visibility = parse_new_visibility(&derive_input);
     inner_new_constructor(&derive_input, visibility)
 }
+
+/// A procedural macro that generates compile-time field name constants for structs.
+///
+/// This macro derives an associated `&'static str` constant per field, a companion
+/// `<Name>Field` enum with one variant per field, and a `field_names` function
+/// returning all field names in declaration order. Raw identifiers are emitted
+/// without their `r#` prefix.
+///
+/// # Supported Attributes
+/// - `#[field_name(skip)]` - Excludes the field from the generated constants and enum
+/// - `#[lombok(serde_names)]` - On the struct, uses each field's `#[serde(rename = "...")]` wire name,
+///   or the name produced by the struct's `#[serde(rename_all = "...")]` rule
+///   as the string value; constant and variant names still follow the Rust field name
+///
+/// # Generated Items
+/// - `FIELD_<NAME>` - An associated `&'static str` constant holding the field name
+/// - `fn field_names() -> &'static [&'static str]` - All non-skipped field names
+/// - `<Name>Field` - An enum with one variant per field, providing `as_str()`, `ALL` and `FromStr`
+///
+/// Variant names are the PascalCase form of the field names. A field whose variant name
+/// would be a keyword, such as `self_` becoming `Self`, or two fields mapping to the same
+/// variant, such as `ab` and `ab_`, are reported as compile errors on the field.
+///
+/// # Examples
+///
+/// ## Basic Usage
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(FieldNames)]
+/// struct User {
+///     user_id: u64,
+///     r#type: String,
+///     #[field_name(skip)]
+///     password: String,
+/// }
+///
+/// assert_eq!(User::FIELD_USER_ID, "user_id");
+/// assert_eq!(User::FIELD_TYPE, "type");
+/// assert_eq!(User::field_names(), &["user_id", "type"]);
+/// ```
+///
+/// ## Field Enum
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(FieldNames)]
+/// struct Point {
+///     x_axis: f64,
+///     y_axis: f64,
+/// }
+///
+/// assert_eq!(PointField::XAxis.as_str(), "x_axis");
+/// assert_eq!(PointField::ALL, &[PointField::XAxis, PointField::YAxis]);
+/// assert_eq!("y_axis".parse::<PointField>(), Ok(PointField::YAxis));
+/// assert!("z_axis".parse::<PointField>().is_err());
+/// ```
+///
+/// ## Serde Wire Names
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, FieldNames)]
+/// #[lombok(serde_names)]
+/// struct Account {
+///     #[serde(rename = "userId")]
+///     user_id: u64,
+///     email: String,
+/// }
+///
+/// assert_eq!(Account::FIELD_USER_ID, "userId");
+/// assert_eq!(AccountField::UserId.as_str(), "userId");
+/// assert_eq!(Account::field_names(), &["userId", "email"]);
+///
+/// #[derive(Deserialize, FieldNames)]
+/// #[lombok(serde_names)]
+/// #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// struct Settings {
+///     max_retries: u8,
+///     #[serde(rename(serialize = "timeoutMs", deserialize = "timeout"))]
+///     timeout_ms: u64,
+/// }
+///
+/// assert_eq!(Settings::field_names(), &["MAX_RETRIES", "timeoutMs"]);
+/// ```
+///
+/// ## Conflicting Variant Names
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(FieldNames)]
+/// struct Pair {
+///     ab: u8,
+///     ab_: u8,
+/// }
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct for which to generate field names.
+///
+/// # Returns
+/// - `TokenStream` - The generated constants, field enum and implementations.
+#[proc_macro_derive(FieldNames, attributes(field_name, lombok))]
+pub fn field_names(input: TokenStream) -> TokenStream {
+    inner_field_names(input)
+}
+
+/// A procedural macro that implements dynamic, name-based field access for structs.
+///
+/// This macro derives the `FieldAccess` trait from the `lombok-macros-runtime` crate,
+/// allowing fields to be read and written by their string name at runtime. Named
+/// fields are addressed by their name without the `r#` prefix, tuple fields by their index.
+///
+/// # Supported Attributes
+/// - `#[field_access(skip)]` - Excludes the field from dynamic access
+///
+/// # Requirements
+/// - The `lombok-macros-runtime` crate must be a dependency of the deriving crate.
+/// - Accessible field types must be `'static`; generic type parameters receive a `'static` bound.
+///
+/// # Examples
+///
+/// ## Reading and Writing Fields
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::FieldAccess;
+///
+/// #[derive(FieldAccess)]
+/// struct Config {
+///     port: u16,
+///     host: String,
+///     #[field_access(skip)]
+///     secret: String,
+/// }
+///
+/// let mut config = Config {
+///     port: 8080,
+///     host: "localhost".to_string(),
+///     secret: "hidden".to_string(),
+/// };
+/// assert_eq!(config.get_field("port").and_then(|value| value.downcast_ref::<u16>()), Some(&8080));
+/// assert!(config.get_field("secret").is_none());
+/// assert_eq!(config.set_field("port", Box::new(9090u16)), Ok(()));
+/// assert_eq!(config.port, 9090);
+/// if let Some(host) = config.get_field_mut("host").and_then(|value| value.downcast_mut::<String>()) {
+///     host.push_str(":9090");
+/// }
+/// assert_eq!(config.host, "localhost:9090");
+/// assert_eq!(config.field_names(), &["port", "host"]);
+/// ```
+///
+/// ## Error Handling
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::{FieldAccess, FieldError};
+///
+/// #[derive(FieldAccess)]
+/// struct Pair(i32, String);
+///
+/// let mut pair = Pair(1, "one".to_string());
+/// assert_eq!(pair.set_field("0", Box::new(2i32)), Ok(()));
+/// assert_eq!(pair.0, 2);
+/// assert!(matches!(
+///     pair.set_field("1", Box::new(3i32)),
+///     Err(FieldError::TypeMismatch { .. })
+/// ));
+/// assert_eq!(
+///     pair.set_field("2", Box::new(3i32)),
+///     Err(FieldError::UnknownField("2".to_string()))
+/// );
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct for which to implement `FieldAccess`.
+///
+/// # Returns
+/// - `TokenStream` - The generated `FieldAccess` implementation.
+#[proc_macro_derive(FieldAccess, attributes(field_access))]
+pub fn field_access(input: TokenStream) -> TokenStream {
+    inner_field_access(input)
+}
+
+/// A procedural macro that generates a field visitor method for structs and enums.
+///
+/// This macro derives a `visit_fields` method that calls `FieldVisitor::visit` from the
+/// `lombok-macros-runtime` crate once per field, passing the field name and a `&dyn Debug`
+/// view of its value. For enums, `FieldVisitor::visit_variant` is called with the active
+/// variant name before its fields are visited. This allows metrics exporters, diffing and
+/// audit logging to be written once for every deriving type.
+///
+/// # Supported Attributes
+/// - `#[visit(skip)]` - Excludes the field from visiting
+/// - `#[visit(rename = "name")]` - Reports the field under the given name; a value that is not
+///   a string literal is a compile error
+///
+/// # Requirements
+/// - The `lombok-macros-runtime` crate must be a dependency of the deriving crate.
+/// - Visited field types must implement `Debug`; generic type parameters receive a `Debug` bound.
+///
+/// # Examples
+///
+/// ## Struct Example
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::FieldVisitor;
+/// use std::fmt::Debug;
+///
+/// #[derive(Default)]
+/// struct Collector(Vec<String>);
+///
+/// impl FieldVisitor for Collector {
+///     fn visit(&mut self, name: &'static str, value: &dyn Debug) {
+///         self.0.push(format!("{name}={value:?}"));
+///     }
+/// }
+///
+/// #[derive(VisitFields)]
+/// struct User {
+///     name: String,
+///     #[visit(rename = "userAge")]
+///     age: u32,
+///     #[visit(skip)]
+///     password: String,
+/// }
+///
+/// let user = User {
+///     name: "Alice".to_string(),
+///     age: 30,
+///     password: "secret".to_string(),
+/// };
+/// let mut collector = Collector::default();
+/// user.visit_fields(&mut collector);
+/// assert_eq!(collector.0, vec!["name=\"Alice\"", "userAge=30"]);
+/// ```
+///
+/// ## Enum Example
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::FieldVisitor;
+/// use std::fmt::Debug;
+///
+/// #[derive(Default)]
+/// struct Collector(Vec<String>);
+///
+/// impl FieldVisitor for Collector {
+///     fn visit(&mut self, name: &'static str, value: &dyn Debug) {
+///         self.0.push(format!("{name}={value:?}"));
+///     }
+///
+///     fn visit_variant(&mut self, name: &'static str) {
+///         self.0.push(name.to_string());
+///     }
+/// }
+///
+/// #[derive(VisitFields)]
+/// enum Event {
+///     Login { user: String, #[visit(skip)] token: String },
+///     Move(i32, i32),
+///     Logout,
+/// }
+///
+/// let mut collector = Collector::default();
+/// Event::Login { user: "bob".to_string(), token: "t".to_string() }.visit_fields(&mut collector);
+/// Event::Move(1, 2).visit_fields(&mut collector);
+/// Event::Logout.visit_fields(&mut collector);
+/// assert_eq!(collector.0, vec!["Login", "user=\"bob\"", "Move", "0=1", "1=2", "Logout"]);
+/// ```
+///
+/// ## Non-String Rename
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(VisitFields)]
+/// struct Config {
+///     #[visit(rename = app_name)]
+///     name: String,
+/// }
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct or enum to visit.
+///
+/// # Returns
+/// - `TokenStream` - The generated `visit_fields` implementation.
+#[proc_macro_derive(VisitFields, attributes(visit))]
+pub fn visit_fields(input: TokenStream) -> TokenStream {
+    inner_visit_fields(input)
+}
+
+/// A procedural macro that generates a per-field change set between two instances of a struct.
+///
+/// This macro derives a `diff` method returning a `Vec<FieldChange>` from the
+/// `lombok-macros-runtime` crate, with one entry per field whose values differ.
+/// Each change carries the field name and the `Debug` renderings of the old and new values.
+///
+/// # Supported Attributes
+/// - `#[diff(skip)]` - Excludes the field from comparison
+/// - `#[debug(skip)]` - Compares the field but redacts both values as `FieldChange::REDACTED`,
+///   so fields hidden from `CustomDebug` output never leak through diffs
+///
+/// # Requirements
+/// - The `lombok-macros-runtime` crate must be a dependency of the deriving crate.
+/// - Compared field types must implement `Debug` and `PartialEq`.
+///
+/// # Examples
+///
+/// ## Basic Usage
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::FieldChange;
+///
+/// #[derive(Clone, CustomDebug, Diff)]
+/// struct Account {
+///     name: String,
+///     balance: u64,
+///     #[debug(skip)]
+///     password: String,
+///     #[diff(skip)]
+///     last_seen: u64,
+/// }
+///
+/// let old = Account {
+///     name: "alice".to_string(),
+///     balance: 10,
+///     password: "old-secret".to_string(),
+///     last_seen: 1,
+/// };
+/// let mut new = old.clone();
+/// new.balance = 20;
+/// new.password = "new-secret".to_string();
+/// new.last_seen = 2;
+/// let changes: Vec<FieldChange> = old.diff(&new);
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0], FieldChange::new("balance", "10".to_string(), "20".to_string()));
+/// assert!(changes[1].is_redacted());
+/// assert_eq!(changes[1].to_string(), "password: [REDACTED] -> [REDACTED]");
+/// ```
+///
+/// ## Tuple Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Diff)]
+/// struct Point(i32, i32);
+///
+/// let changes = Point(1, 2).diff(&Point(1, 3));
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].field, "1");
+/// assert!(Point(1, 2).diff(&Point(1, 2)).is_empty());
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct to compare.
+///
+/// # Returns
+/// - `TokenStream` - The generated `diff` implementation.
+#[proc_macro_derive(Diff, attributes(diff, debug))]
+pub fn diff(input: TokenStream) -> TokenStream {
+    inner_diff(input)
+}
+
+/// A procedural macro that turns a struct into an immutable value type.
+///
+/// This macro mirrors Lombok's `@Value` and combines, in a single derive, what
+/// `Getter`, `New` and `CustomDebug` generate, plus `PartialEq`, `Eq` and `Hash`
+/// implementations comparing every field. No setters or mutable getters are generated.
+///
+/// # Supported Attributes
+/// - `#[get(...)]` - Controls getter generation, exactly as for `Getter`
+/// - `#[new(...)]` - Controls constructor visibility and skipped fields, exactly as for `New`
+/// - `#[debug(skip)]` - Excludes the field from the debug output
+///
+/// # Rejected Attributes
+/// - `#[set(...)]` and `#[get_mut(...)]` - Mutating accessors fail compilation with an explanatory error
+///
+/// # Equality and Hashing
+/// `Eq` and `Hash` are always implemented, so every field type must implement both.
+/// Fields such as `f64` or `HashMap<K, V>` are therefore not supported; combine `Getter`,
+/// `New` and `CustomDebug` instead for such structs.
+///
+/// # Examples
+///
+/// ## Basic Usage
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::collections::HashSet;
+///
+/// #[derive(Value)]
+/// struct Money {
+///     amount: u64,
+///     currency: String,
+///     #[debug(skip)]
+///     #[get(skip)]
+///     internal_id: u32,
+/// }
+///
+/// let price = Money::new(100, "EUR".to_string(), 1);
+/// assert_eq!(*price.get_amount(), 100);
+/// assert_eq!(price.get_currency(), "EUR");
+/// assert_eq!(format!("{price:?}"), "Money { amount: 100, currency: \"EUR\" }");
+/// assert_eq!(price, Money::new(100, "EUR".to_string(), 1));
+/// assert_ne!(price, Money::new(100, "USD".to_string(), 1));
+/// let unique: HashSet<Money> = [price, Money::new(100, "EUR".to_string(), 1)].into_iter().collect();
+/// assert_eq!(unique.len(), 1);
+/// ```
+///
+/// ## No Setters
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(Value)]
+/// struct Money {
+///     amount: u64,
+/// }
+///
+/// let mut price = Money::new(100);
+/// price.set_amount(200);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct to turn into a value type.
+///
+/// # Returns
+/// - `TokenStream` - The generated getters, constructor and trait implementations.
+#[proc_macro_derive(Value, attributes(get, get_mut, set, new, debug, default, lombok))]
+pub fn value(input: TokenStream) -> TokenStream {
+    inner_value(input)
+}
+
+/// A procedural macro that forwards methods to struct fields.
+///
+/// This macro mirrors Lombok's `@Delegate` for composition: each field marked with
+/// `#[delegate(...)]` gets its listed methods forwarded from the outer struct, either
+/// as inherent methods or as an implementation of a trait.
+///
+/// # Supported Attributes
+/// - `#[delegate(methods(len, is_empty))]` - Generates public inherent methods calling `self.field.len()` and `self.field.is_empty()`
+/// - `#[delegate(pub(crate), methods(...))]` - Sets the visibility of the inherent methods, parsed like `#[get(...)]`
+/// - `#[delegate(methods(fn get(&self, index: usize) -> Option<&u8>))]` - Declares the full signature of a forwarded method
+/// - `#[delegate(trait = Read, methods(fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>))]` - Implements the trait for the struct by forwarding to the field
+///
+/// # Method Signatures
+/// A derive macro cannot see the signatures of foreign methods, so they must be declared as
+/// `fn name(&self, arg: Type) -> Type` or `fn name(&mut self, arg: Type) -> Type`.
+/// Only these collection methods may be listed by name alone:
+/// - `len` - `fn len(&self) -> usize`
+/// - `is_empty` - `fn is_empty(&self) -> bool`
+/// - `iter` - `fn iter(&self)` returning the `IntoIterator::IntoIter` of `&T`
+/// - `iter_mut` - `fn iter_mut(&mut self)` returning the `IntoIterator::IntoIter` of `&mut T`
+/// - `clear` - `fn clear(&mut self)`
+///
+/// Trait delegation always requires full signatures.
+///
+/// # Examples
+///
+/// ## Inherent Methods
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Delegate)]
+/// struct Playlist {
+///     name: String,
+///     #[delegate(methods(len, is_empty, iter, clear))]
+///     #[delegate(pub(crate), methods(fn push(&mut self, song: String), fn first(&self) -> Option<&String>))]
+///     songs: Vec<String>,
+/// }
+///
+/// let mut playlist = Playlist {
+///     name: "mix".to_string(),
+///     songs: Vec::new(),
+/// };
+/// assert!(playlist.is_empty());
+/// playlist.push("intro".to_string());
+/// playlist.push("outro".to_string());
+/// assert_eq!(playlist.len(), 2);
+/// assert_eq!(playlist.first().map(String::as_str), Some("intro"));
+/// assert_eq!(playlist.iter().count(), 2);
+/// playlist.clear();
+/// assert!(playlist.is_empty());
+/// assert_eq!(playlist.name, "mix");
+/// ```
+///
+/// ## Trait Implementations
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::io::{Cursor, Read};
+///
+/// #[derive(Delegate)]
+/// struct CountingReader(
+///     #[delegate(trait = Read, methods(fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>))]
+///     Cursor<Vec<u8>>,
+/// );
+///
+/// let mut reader = CountingReader(Cursor::new(b"hello".to_vec()));
+/// let mut text = String::new();
+/// reader.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "hello");
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct whose fields are delegated to.
+///
+/// # Returns
+/// - `TokenStream` - The generated forwarding methods and trait implementations.
+#[proc_macro_derive(Delegate, attributes(delegate))]
+pub fn delegate(input: TokenStream) -> TokenStream {
+    inner_delegate(input)
+}
+
+/// A procedural macro that turns a struct into a transparent wrapper around one of its fields.
+///
+/// This macro removes the boilerplate of newtype wrappers by implementing `Deref`,
+/// `AsRef<T>`, `Borrow<T>` and `From<T>` for the wrapped field type `T`, together with an
+/// `into_inner(self) -> T` method using the struct's visibility.
+///
+/// The wrapped field is the only field of the struct, or the field marked with `#[deref]`.
+/// When the struct has other fields, `From<T>` fills them with `Default::default()`.
+///
+/// # Supported Attributes
+/// - `#[deref]` - Marks the wrapped field of a struct with several fields
+/// - `#[deref(mut)]` - Marks the wrapped field and also implements `DerefMut` and `AsMut<T>`
+///
+/// # Examples
+///
+/// ## Tuple Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::collections::HashSet;
+///
+/// #[derive(Debug, Eq, Hash, Newtype, PartialEq)]
+/// struct UserId(String);
+///
+/// let id: UserId = UserId::from("u-1".to_string());
+/// assert_eq!(id.len(), 3);
+/// assert!(id.starts_with("u-"));
+/// let ids: HashSet<UserId> = [id].into_iter().collect();
+/// assert!(ids.contains(&"u-1".to_string()));
+/// let id: UserId = ids.into_iter().next().unwrap();
+/// assert_eq!(id.into_inner(), "u-1");
+/// ```
+///
+/// ## Mutable Access
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Newtype)]
+/// struct Scores(#[deref(mut)] Vec<u32>);
+///
+/// let mut scores: Scores = vec![1, 2].into();
+/// scores.push(3);
+/// scores.as_mut().retain(|score| *score > 1);
+/// assert_eq!(scores.as_ref(), &vec![2, 3]);
+/// ```
+///
+/// ## Named Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Newtype)]
+/// struct Tagged {
+///     #[deref]
+///     value: u64,
+///     hits: u32,
+/// }
+///
+/// let tagged: Tagged = Tagged::from(42);
+/// assert_eq!(*tagged + 1, 43);
+/// assert_eq!(tagged.hits, 0);
+/// assert_eq!(tagged.into_inner(), 42);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the wrapper struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated trait implementations and `into_inner` method.
+#[proc_macro_derive(Newtype, attributes(deref))]
+pub fn newtype(input: TokenStream) -> TokenStream {
+    inner_newtype(input)
+}
+
+/// A procedural macro that generates conversions into a struct from other structs, field by field.
+///
+/// Each field is taken from the source field with the same name (or index for tuple structs)
+/// and converted with `Into::into`, so identical and convertible field types both work.
+///
+/// # Supported Attributes
+/// - `#[map_from(ApiUser, DbUser)]` - On the struct, implements `From<ApiUser>` and `From<DbUser>`
+/// - `#[try_map_from(ApiUser)]` - On the struct, implements `TryFrom<ApiUser>` with `Box<dyn Error + Send + Sync>` as error
+/// - `#[try_map_from(ApiUser, error = MyError)]` - Implements `TryFrom<ApiUser>` with a custom error type
+/// - `#[map(rename = "src_field")]` - Reads the value from a differently named source field
+/// - `#[map(skip)]` - Does not read the source and uses `Default::default()`
+/// - `#[map(skip, default = expr)]` - Does not read the source and uses `expr`
+/// - `#[map(with = path::to_fn)]` - Converts the source field with `to_fn(source.field)`
+/// - `#[map(try_with = path::to_fn)]` - In `TryFrom` implementations, converts with `to_fn(source.field)?`;
+///   the error of the function must convert into the implementation's error type; `#[map_from(...)]`
+///   sources fail compilation unless the field also declares `with = ...`
+///
+/// # Examples
+///
+/// ## Infallible Conversions
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// struct UserDto {
+///     id: u32,
+///     user_name: String,
+///     email: String,
+///     tags: Vec<&'static str>,
+/// }
+///
+/// fn count_chars(value: String) -> usize {
+///     value.chars().count()
+/// }
+///
+/// #[derive(MapFrom)]
+/// #[map_from(UserDto)]
+/// struct User {
+///     id: u64,
+///     #[map(rename = "user_name")]
+///     name: String,
+///     #[map(rename = "email", with = count_chars)]
+///     email_len: usize,
+///     #[map(skip, default = true)]
+///     active: bool,
+///     #[map(skip)]
+///     logins: u32,
+///     #[map(with = Vec::into_boxed_slice)]
+///     tags: Box<[&'static str]>,
+/// }
+///
+/// let user: User = UserDto {
+///     id: 7,
+///     user_name: "ada".to_string(),
+///     email: "ada@example.com".to_string(),
+///     tags: vec!["admin"],
+/// }
+/// .into();
+/// assert_eq!(user.id, 7);
+/// assert_eq!(user.name, "ada");
+/// assert_eq!(user.email_len, 15);
+/// assert!(user.active);
+/// assert_eq!(user.logins, 0);
+/// assert_eq!(user.tags.len(), 1);
+/// ```
+///
+/// ## Fallible Conversions
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use std::num::ParseIntError;
+///
+/// struct PortForm(String, String);
+///
+/// fn parse_port(value: String) -> Result<u16, ParseIntError> {
+///     value.parse()
+/// }
+///
+/// #[derive(Debug, MapFrom)]
+/// #[try_map_from(PortForm, error = ParseIntError)]
+/// struct Port(String, #[map(try_with = parse_port)] u16);
+///
+/// let port: Port = Port::try_from(PortForm("http".to_string(), "8080".to_string())).unwrap();
+/// assert_eq!(port.0, "http");
+/// assert_eq!(port.1, 8080);
+/// assert!(Port::try_from(PortForm("http".to_string(), "port".to_string())).is_err());
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the target struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated `From` and `TryFrom` implementations.
+#[proc_macro_derive(MapFrom, attributes(map_from, try_map_from, map))]
+pub fn map_from(input: TokenStream) -> TokenStream {
+    inner_map_from(input)
+}
+
+/// A procedural macro that implements `Default` with per-field default expressions.
+///
+/// Unlike `#[derive(Default)]`, every field can declare its own default value. Fields
+/// without one use `Default::default()`. The same `#[default(expr)]` is used by `New`
+/// for fields marked `#[new(skip)]`.
+///
+/// # Supported Attributes
+/// - `#[default(expr)]` - On a field, initializes it with `expr`
+/// - `#[default]` - On an enum variant, selects the variant returned by `default()`; exactly one variant must be marked
+///
+/// # Examples
+///
+/// ## Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(New, SmartDefault)]
+/// struct Settings {
+///     name: String,
+///     #[new(skip)]
+///     #[default(8080)]
+///     port: u16,
+///     #[new(skip)]
+///     #[default(vec!["localhost".to_string()])]
+///     hosts: Vec<String>,
+///     #[new(skip)]
+///     verbose: bool,
+/// }
+///
+/// let settings = Settings::default();
+/// assert_eq!(settings.name, "");
+/// assert_eq!(settings.port, 8080);
+/// assert_eq!(settings.hosts, vec!["localhost"]);
+/// assert!(!settings.verbose);
+/// let custom = Settings::new("api".to_string());
+/// assert_eq!(custom.port, 8080);
+/// ```
+///
+/// ## Enums
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Debug, PartialEq, SmartDefault)]
+/// enum Retry {
+///     Never,
+///     #[default]
+///     Limited {
+///         #[default(3)]
+///         attempts: u8,
+///         backoff_ms: u64,
+///     },
+/// }
+///
+/// assert_eq!(Retry::default(), Retry::Limited { attempts: 3, backoff_ms: 0 });
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct or enum.
+///
+/// # Returns
+/// - `TokenStream` - The generated `Default` implementation.
+#[proc_macro_derive(SmartDefault, attributes(default))]
+pub fn smart_default(input: TokenStream) -> TokenStream {
+    inner_smart_default(input)
+}
+
+/// A procedural macro that converts a struct to and from a string-keyed property map.
+///
+/// This macro derives `to_map(&self) -> BTreeMap<&'static str, String>`, rendering every
+/// field with `ToString`, and `from_map(&HashMap<String, String>) -> Result<Self, PropertyError>`,
+/// parsing every field with `FromStr`. `PropertyError` comes from the `lombok-macros-runtime` crate.
+///
+/// # Supported Attributes
+/// - `#[prop(rename = "key")]` - Uses `key` instead of the field name
+/// - `#[prop(default)]` - Uses `Default::default()` when the property is missing
+/// - `#[prop(default = expr)]` - Uses `expr` when the property is missing
+/// - `#[prop(skip)]` - Leaves the field out of `to_map` and never reads it in `from_map`,
+///   initializing it with its default
+///
+/// # Field Types
+/// - Field types must implement `ToString` and `FromStr`, with a `FromStr::Err` implementing `Display`.
+/// - `Option<T>` fields are optional: `None` is left out of `to_map` and a missing property becomes `None`.
+/// - Other fields without a default report `PropertyError::MissingProperty` when absent.
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::PropertyError;
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, Properties)]
+/// struct ServerConfig {
+///     host: String,
+///     #[prop(default = 8080)]
+///     port: u16,
+///     #[prop(rename = "log.level")]
+///     log_level: Option<String>,
+///     #[prop(skip)]
+///     connections: usize,
+/// }
+///
+/// let mut map: HashMap<String, String> = HashMap::new();
+/// map.insert("host".to_string(), "localhost".to_string());
+/// let config: ServerConfig = ServerConfig::from_map(&map).unwrap();
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.log_level, None);
+/// let properties = config.to_map();
+/// assert_eq!(properties.get("port").map(String::as_str), Some("8080"));
+/// assert!(!properties.contains_key("log.level"));
+/// assert!(!properties.contains_key("connections"));
+///
+/// map.insert("port".to_string(), "http".to_string());
+/// let error: PropertyError = ServerConfig::from_map(&map).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid value 'http' for property 'port': invalid digit found in string"
+/// );
+/// assert_eq!(
+///     ServerConfig::from_map(&HashMap::new()).unwrap_err(),
+///     PropertyError::MissingProperty("host")
+/// );
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated `to_map` and `from_map` methods.
+#[proc_macro_derive(Properties, attributes(prop))]
+pub fn properties(input: TokenStream) -> TokenStream {
+    inner_properties(input)
+}
+
+/// A procedural macro that builds a struct from environment variables.
+///
+/// This macro derives `from_env() -> Result<Self, EnvError>`, reading every field from an
+/// environment variable and parsing it with `FromStr`. Reading does not stop at the first
+/// failure: the returned `EnvError` lists every missing and invalid variable.
+/// `EnvError` and `EnvVarError` come from the `lombok-macros-runtime` crate.
+///
+/// # Supported Attributes
+/// - `#[env(prefix = "APP_")]` - On the struct, prepends `APP_` to every variable name
+/// - `#[env(name = "PORT")]` - Reads `PORT` instead of the uppercased field name
+/// - `#[env(default = "8080")]` - Parses `"8080"` when the variable is not set; number, boolean
+///   and character literals such as `default = 8080` are parsed from their text the same way
+///
+/// # Field Types
+/// - Field types must implement `FromStr`, with a `FromStr::Err` implementing `Display`.
+/// - `Option<T>` fields are optional: an unset variable without a default becomes `None`.
+/// - Other fields without a default report `EnvVarError::Missing` when unset.
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::EnvError;
+///
+/// #[derive(Debug, FromEnv)]
+/// #[env(prefix = "DOC_APP_")]
+/// struct AppConfig {
+///     host: String,
+///     #[env(name = "PORT", default = 8080)]
+///     port: u16,
+///     log_level: Option<String>,
+/// }
+///
+/// let error: EnvError = AppConfig::from_env().unwrap_err();
+/// assert_eq!(error.missing(), vec!["DOC_APP_HOST"]);
+///
+/// unsafe {
+///     std::env::set_var("DOC_APP_HOST", "localhost");
+/// }
+/// let config: AppConfig = AppConfig::from_env().unwrap();
+/// assert_eq!(config.host, "localhost");
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.log_level, None);
+///
+/// unsafe {
+///     std::env::remove_var("DOC_APP_HOST");
+///     std::env::set_var("DOC_APP_PORT", "http");
+///     std::env::set_var("DOC_APP_LOG_LEVEL", "debug");
+/// }
+/// let error: EnvError = AppConfig::from_env().unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Missing environment variable: DOC_APP_HOST; \
+///      Invalid value 'http' for environment variable 'DOC_APP_PORT': invalid digit found in string"
+/// );
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated `from_env` constructor.
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn from_env(input: TokenStream) -> TokenStream {
+    inner_from_env(input)
+}
+
+/// A procedural macro that exposes getters through a trait instead of inherent methods.
+///
+/// This macro declares a trait containing one getter per field and implements it for the
+/// struct, so the getters can be mocked or called through `dyn` dispatch. Each getter follows
+/// the field's `#[get(...)]` attribute exactly as `#[derive(Getter)]` does, including the
+/// return type, lazy, atomic and interior-mutability options. The visibility options of
+/// `#[get(...)]` are ignored because trait methods share the trait's visibility, which is
+/// the visibility of the struct.
+///
+/// # Supported Attributes
+/// - `#[getter_trait(name = "UserView")]` - Names the trait, `{Struct}Getters` by default
+/// - `#[getter_trait(name = "path::UserView", impl_only)]` - Implements an existing trait
+///   without declaring it, so several structs can share one accessor interface
+/// - `#[get(skip)]` - Leaves the field out of the trait
+/// - `#[get(type(clone))]`, `#[get(type(copy))]`, `#[get(type(deref))]` - Change the return type
+///
+/// # Generics
+/// The declared trait takes the generic parameters of the struct, so `struct Page<'a, T>`
+/// declares `trait PageGetters<'a, T>`. With `impl_only`, the trait path names its own
+/// arguments, such as `name = "View<'a>"`.
+///
+/// # Limitations
+/// - Only structs with named fields are supported.
+/// - Structs using `impl_only` must produce the same getter signatures as the trait.
+///
+/// # Examples
+///
+/// ## Basic Usage
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(GetterTrait)]
+/// #[getter_trait(name = "UserView")]
+/// struct User {
+///     name: String,
+///     #[get(type(copy))]
+///     age: u32,
+///     #[get(skip)]
+///     password: String,
+/// }
+///
+/// #[derive(GetterTrait)]
+/// #[getter_trait(name = "UserView", impl_only)]
+/// struct Admin {
+///     name: String,
+///     #[get(type(copy))]
+///     age: u32,
+/// }
+///
+/// fn describe(user: &dyn UserView) -> String {
+///     format!("{} ({})", user.get_name(), user.get_age())
+/// }
+///
+/// let user: User = User {
+///     name: "Alice".to_string(),
+///     age: 30,
+///     password: "secret".to_string(),
+/// };
+/// let admin: Admin = Admin {
+///     name: "Root".to_string(),
+///     age: 40,
+/// };
+/// assert_eq!(describe(&user), "Alice (30)");
+/// assert_eq!(describe(&admin), "Root (40)");
+/// assert_eq!(user.password, "secret");
+/// ```
+///
+/// ## Generic Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(GetterTrait)]
+/// struct Page<'a, T: Clone> {
+///     title: &'a str,
+///     #[get(type(clone))]
+///     items: Vec<T>,
+/// }
+///
+/// fn first_title<'a, T: Clone>(pages: &[&dyn PageGetters<'a, T>]) -> &'a str {
+///     pages[0].get_title()
+/// }
+///
+/// let page: Page<u8> = Page { title: "intro", items: vec![1, 2] };
+/// assert_eq!(first_title(&[&page]), "intro");
+/// assert_eq!(page.get_items(), vec![1, 2]);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated trait declaration and implementation.
+#[proc_macro_derive(GetterTrait, attributes(getter_trait, get))]
+pub fn getter_trait(input: TokenStream) -> TokenStream {
+    inner_getter_trait(input)
+}
+
+/// A procedural macro that generates borrowed views over subsets of a struct's fields.
+///
+/// Every `#[view(...)]` attribute declares a struct holding one reference per listed field
+/// and a method `as_{view_name}` returning it, with the view name converted to snake_case.
+/// A view marked `mut` holds `&mut` references and its method, `as_{view_name}_mut`, takes
+/// `&mut self`, which borrows several fields mutably at once where individual `get_mut_*`
+/// calls cannot. The views and methods share the visibility of the struct.
+///
+/// Views of a generic struct take the struct's generics after the `'view` lifetime, such as
+/// `PageHeader<'view, 'a, T>`, and hold a `PhantomData` marker so every parameter stays in use.
+///
+/// # Supported Attributes
+/// - `#[view(name = "UserPublic", fields(name, email))]` - Generates `UserPublic<'view>`
+///   with `&'view` fields and `as_user_public(&self)`
+/// - `#[view(name = "UserEdit", fields(name, email), mut)]` - Generates `UserEdit<'view>`
+///   with `&'view mut` fields and `as_user_edit_mut(&mut self)`
+///
+/// # Limitations
+/// - Only structs with named fields are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(View)]
+/// #[view(name = "UserPublic", fields(name, email))]
+/// #[view(name = "UserEdit", fields(name, email, age), mut)]
+/// struct User {
+///     name: String,
+///     email: String,
+///     age: u32,
+///     password: String,
+/// }
+///
+/// fn greeting(user: UserPublic<'_>) -> String {
+///     format!("{} <{}>", user.name, user.email)
+/// }
+///
+/// let mut user: User = User {
+///     name: "Alice".to_string(),
+///     email: "alice@example.com".to_string(),
+///     age: 30,
+///     password: "secret".to_string(),
+/// };
+/// assert_eq!(greeting(user.as_user_public()), "Alice <alice@example.com>");
+///
+/// let edit: UserEdit<'_> = user.as_user_edit_mut();
+/// edit.name.push_str(" Smith");
+/// edit.email.insert_str(0, "a.");
+/// *edit.age += 1;
+/// assert_eq!(user.name, "Alice Smith");
+/// assert_eq!(user.email, "a.alice@example.com");
+/// assert_eq!(user.age, 31);
+/// assert_eq!(user.password, "secret");
+/// ```
+///
+/// ## Generic Structs
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(View)]
+/// #[view(name = "PageHeader", fields(title))]
+/// struct Page<'a, T> {
+///     title: &'a str,
+///     items: Vec<T>,
+/// }
+///
+/// let page: Page<'_, u32> = Page {
+///     title: "Home",
+///     items: vec![1, 2],
+/// };
+/// let header: PageHeader<'_, '_, u32> = page.as_page_header();
+/// assert_eq!(*header.title, "Home");
+/// assert_eq!(page.items.len(), 2);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated view structs and methods.
+#[proc_macro_derive(View, attributes(view))]
+pub fn view(input: TokenStream) -> TokenStream {
+    inner_view(input)
+}
+
+/// A procedural macro that generates a patch struct for partial updates.
+///
+/// This macro generates `{Struct}Patch`, holding every field as `Option<T>`, and
+/// `apply(&mut self, patch) -> &mut Self`, which calls the field's generated `set_*` method
+/// for every `Some` value. Fields that are already `Option<T>` become `Option<Option<T>>`,
+/// so `Some(None)` clears the field while `None` leaves it untouched. The patch struct
+/// always implements `Default` with every field set to `None`.
+///
+/// The setters are not generated by this macro: the struct must also derive `Setter` or `Data`.
+///
+/// # Supported Attributes
+/// - `#[patch(name = "UserUpdate")]` - Names the patch struct, `{Struct}Patch` by default
+/// - `#[patch(derive(Debug, Deserialize))]` - Adds derives to the patch struct
+/// - `#[set(skip)]` - Leaves the field out of the patch struct, making it non-patchable
+///
+/// The patch struct of a generic struct takes the same generics. If a generic parameter only
+/// appears in skipped fields, the patch struct also holds a private `PhantomData` marker, so it
+/// must then be built with `..Default::default()`.
+///
+/// # Limitations
+/// - Only structs with named fields are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Debug, Patch, Setter)]
+/// #[patch(derive(Debug, serde::Deserialize))]
+/// struct User {
+///     #[set(skip)]
+///     id: u64,
+///     name: String,
+///     nickname: Option<String>,
+/// }
+///
+/// let mut user: User = User {
+///     id: 1,
+///     name: "Alice".to_string(),
+///     nickname: Some("Al".to_string()),
+/// };
+/// user.apply(UserPatch {
+///     name: Some("Alicia".to_string()),
+///     ..Default::default()
+/// });
+/// assert_eq!(user.name, "Alicia");
+/// assert_eq!(user.nickname, Some("Al".to_string()));
+///
+/// user.apply(UserPatch {
+///     nickname: Some(None),
+///     ..Default::default()
+/// });
+/// assert_eq!(user.nickname, None);
+/// assert_eq!(user.id, 1);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated patch struct and `apply` method.
+#[proc_macro_derive(Patch, attributes(patch, set))]
+pub fn patch(input: TokenStream) -> TokenStream {
+    inner_patch(input)
+}
+
+/// A procedural macro that implements `Merge` for layered configuration.
+///
+/// This macro implements the `Merge` trait from the `lombok-macros-runtime` crate, whose
+/// `merge(&mut self, other: Self)` combines `other` into `self` field by field, and whose
+/// `merged(self, other: Self) -> Self` returns the result. Layers are merged from the lowest
+/// to the highest priority, such as defaults, then the file configuration, then CLI overrides.
+///
+/// # Supported Attributes
+/// - `#[merge(overwrite)]` - Replaces the field with the other value, the default for non-`Option` fields
+/// - `#[merge(keep)]` - Keeps the current value
+/// - `#[merge(option)]` - Takes the other value only when it is `Some`, the default for `Option<T>` fields
+/// - `#[merge(append)]` - Extends the field with the other value through `Extend`, for collections
+/// - `#[merge(nested)]` - Merges the other value recursively through the field type's `Merge` implementation
+/// - `#[merge(with = path)]` - Calls `path(&mut self.field, other.field)`
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+/// use lombok_macros_runtime::Merge;
+///
+/// #[derive(Debug, Default, Merge)]
+/// struct Logging {
+///     level: Option<String>,
+///     #[merge(append)]
+///     targets: Vec<String>,
+/// }
+///
+/// fn max(current: &mut u32, other: u32) {
+///     *current = (*current).max(other);
+/// }
+///
+/// #[derive(Debug, Default, Merge)]
+/// struct Config {
+///     #[merge(keep)]
+///     name: &'static str,
+///     port: Option<u16>,
+///     #[merge(with = max)]
+///     workers: u32,
+///     #[merge(nested)]
+///     logging: Logging,
+/// }
+///
+/// let defaults: Config = Config {
+///     name: "app",
+///     port: Some(8080),
+///     workers: 4,
+///     logging: Logging {
+///         level: Some("info".to_string()),
+///         targets: vec!["stdout".to_string()],
+///     },
+/// };
+/// let file: Config = Config {
+///     name: "file",
+///     port: None,
+///     workers: 2,
+///     logging: Logging {
+///         level: None,
+///         targets: vec!["app.log".to_string()],
+///     },
+/// };
+/// let cli: Config = Config {
+///     port: Some(9090),
+///     workers: 8,
+///     logging: Logging {
+///         level: Some("debug".to_string()),
+///         targets: Vec::new(),
+///     },
+///     ..Default::default()
+/// };
+/// let config: Config = defaults.merged(file).merged(cli);
+/// assert_eq!(config.name, "app");
+/// assert_eq!(config.port, Some(9090));
+/// assert_eq!(config.workers, 8);
+/// assert_eq!(config.logging.level.as_deref(), Some("debug"));
+/// assert_eq!(config.logging.targets, vec!["stdout", "app.log"]);
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated `Merge` implementation.
+#[proc_macro_derive(Merge, attributes(merge))]
+pub fn merge(input: TokenStream) -> TokenStream {
+    inner_merge(input)
+}
+
+/// A procedural macro that generates a typestate builder checking required fields at compile time.
+///
+/// This macro generates `{Struct}Builder` and `builder()`. The builder carries one marker type
+/// parameter per required field, `BuilderUnset` until the field's setter is called and
+/// `BuilderSet<T>` afterwards, so `build()` only exists once every required field is set and
+/// setting a required field twice does not compile. `BuilderUnset` and `BuilderSet` come from
+/// the `lombok-macros-runtime` crate. The builder, `builder()` and `build()` share the
+/// visibility of the struct.
+///
+/// # Supported Attributes
+/// - `#[builder(default)]` - Makes the field optional, using `Default::default()` when unset
+/// - `#[builder(default = expr)]` - Makes the field optional, using `expr` when unset
+/// - `#[builder(skip)]` - Generates no setter and initializes the field with its default
+/// - `#[builder(pub)]`, `#[builder(pub(crate))]`, `#[builder(private)]` - Sets the setter visibility
+/// - `#[builder(type(Into<String>))]`, `#[builder(type(AsRef<str>))]` - Sets the setter parameter
+///   type, converting it exactly as setters with the same `type(...)` option do
+/// - `#[builder(flatten)]` - On a field whose type also derives `TypedBuilder`, makes the setter
+///   take a closure that receives the field type's builder, so the nested fields are set in place
+///   and their required fields are checked at compile time too; the nested setters stay on the
+///   nested builder rather than the outer one, since a derive cannot see the fields of another type
+///
+/// The struct also implements `TypedBuild`, and its complete builder implements `Build`, both from
+/// the `lombok-macros-runtime` crate. Flattened setters rely on these traits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Debug, TypedBuilder)]
+/// struct Request {
+///     #[builder(type(Into<String>))]
+///     url: String,
+///     method: &'static str,
+///     #[builder(default = 30)]
+///     timeout: u64,
+///     #[builder(default)]
+///     headers: Vec<(String, String)>,
+///     #[builder(skip)]
+///     retries: u32,
+/// }
+///
+/// let request: Request = Request::builder()
+///     .timeout(5)
+///     .url("https://example.com")
+///     .method("GET")
+///     .build();
+/// assert_eq!(request.url, "https://example.com");
+/// assert_eq!(request.method, "GET");
+/// assert_eq!(request.timeout, 5);
+/// assert!(request.headers.is_empty());
+/// assert_eq!(request.retries, 0);
+/// ```
+///
+/// Flattened fields take a closure building the nested struct:
+///
+/// ```rust
+/// use lombok_macros::*;
+///
+/// #[derive(Debug, TypedBuilder)]
+/// struct Metadata {
+///     author: String,
+///     #[builder(default = 1)]
+///     version: u32,
+/// }
+///
+/// #[derive(Debug, TypedBuilder)]
+/// struct Document {
+///     title: String,
+///     #[builder(flatten)]
+///     metadata: Metadata,
+/// }
+///
+/// let document: Document = Document::builder()
+///     .metadata(|metadata| metadata.author("alice".to_string()))
+///     .title("Guide".to_string())
+///     .build();
+/// assert_eq!(document.metadata.author, "alice");
+/// assert_eq!(document.metadata.version, 1);
+/// ```
+///
+/// Calling `build()` before every required field is set does not compile:
+///
+/// ```rust,compile_fail
+/// use lombok_macros::*;
+///
+/// #[derive(TypedBuilder)]
+/// struct Request {
+///     url: String,
+///     method: &'static str,
+/// }
+///
+/// let request: Request = Request::builder().url("https://example.com".to_string()).build();
+/// ```
+///
+/// # Arguments
+/// - `input` - The input token stream representing the struct.
+///
+/// # Returns
+/// - `TokenStream` - The generated builder.
+#[proc_macro_derive(TypedBuilder, attributes(builder))]
+pub fn typed_builder(input: TokenStream) -> TokenStream {
+    inner_typed_builder(input)
+}
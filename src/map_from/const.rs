@@ -0,0 +1,21 @@
+/// Error message indicating that #[derive(MapFrom)] is only supported for structs.
+pub(crate) const UNSUPPORTED_MAP_FROM_DERIVE: &str =
+    "#[derive(MapFrom)] is only supported for structs.";
+
+/// Error message indicating that a conversion source list could not be parsed.
+pub(crate) const INVALID_MAP_FROM_SOURCES: &str = "Invalid #[map_from(...)] or #[try_map_from(...)] list, expected source types and an optional `error = Type`";
+
+/// Error message indicating that #[derive(MapFrom)] was used without any conversion source.
+pub(crate) const MISSING_MAP_FROM_SOURCE: &str =
+    "#[derive(MapFrom)] requires at least one #[map_from(Type)] or #[try_map_from(Type)] attribute";
+
+/// Error message indicating that an infallible conversion declares an error type.
+pub(crate) const MAP_FROM_ERROR_NOT_ALLOWED: &str =
+    "#[map_from(...)] is infallible and does not accept `error = Type`, use #[try_map_from(...)]";
+
+/// Error message indicating that a fallible field conversion is used by an infallible implementation.
+pub(crate) const MAP_TRY_WITH_REQUIRES_TRY_MAP_FROM: &str = "#[map(try_with = ...)] is only applied by #[try_map_from(...)] conversions, add `with = ...` for #[map_from(...)] sources";
+
+/// Error message indicating that a field mapping cannot be renamed on a tuple struct.
+pub(crate) const MAP_RENAME_REQUIRES_NAMED_FIELD: &str =
+    "#[map(rename = ...)] is only supported on named fields";
@@ -0,0 +1,169 @@
+use crate::*;
+
+/// Parses the source types and optional `error = Type` of a `#[map_from(...)]` or `#[try_map_from(...)]` attribute.
+///
+/// # Arguments
+///
+/// - `ParseStream` - The attribute arguments to parse.
+///
+/// # Returns
+///
+/// - `syn::Result<(Vec<Type>, Option<Type>)>` - The source types and the declared error type.
+fn parse_map_from_sources(input: ParseStream) -> syn::Result<(Vec<Type>, Option<Type>)> {
+    let mut sources: Vec<Type> = Vec::new();
+    let mut error: Option<Type> = None;
+    while !input.is_empty() {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            if key != ERROR {
+                return Err(syn::Error::new(key.span(), INVALID_MAP_FROM_SOURCES));
+            }
+            input.parse::<Token![=]>()?;
+            error = Some(input.parse()?);
+        } else {
+            sources.push(input.parse()?);
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok((sources, error))
+}
+
+/// Collects the conversion sources declared with the attributes named `attr_name`.
+///
+/// # Arguments
+///
+/// - `&[Attribute]` - The attributes of the struct.
+/// - `&str` - The attribute name, `map_from` or `try_map_from`.
+///
+/// # Returns
+///
+/// - `Vec<(Type, Option<Type>)>` - Each source type paired with the error type of its attribute.
+fn collect_map_from_sources(attrs: &[Attribute], attr_name: &str) -> Vec<(Type, Option<Type>)> {
+    let mut sources: Vec<(Type, Option<Type>)> = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(attr_name)) {
+        let (types, error): (Vec<Type>, Option<Type>) = attr
+            .parse_args_with(parse_map_from_sources)
+            .unwrap_or_else(|error| panic!("{}: {}", INVALID_MAP_FROM_SOURCES, error));
+        sources.extend(types.into_iter().map(|ty| (ty, error.clone())));
+    }
+    sources
+}
+
+/// Generates the value of a single field converted from the source.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to generate the value for.
+/// - `usize` - The index of the field, used as the source member for tuple fields.
+/// - `bool` - Whether the value is generated for a `TryFrom` implementation.
+///
+/// # Returns
+///
+/// - `syn::Result<TokenStream2>` - The generated `member: value` initializer.
+fn build_field_mapping(field: &Field, index: usize, fallible: bool) -> syn::Result<TokenStream2> {
+    let member: Member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    };
+    let default: Option<TokenStream2> = parse_attribute_value(&field.attrs, MAP, DEFAULT);
+    if parse_attribute_flags(&field.attrs, MAP)
+        .iter()
+        .any(|flag| flag == SKIP)
+        || default.is_some()
+    {
+        let value: TokenStream2 =
+            default.unwrap_or_else(|| quote! { std::default::Default::default() });
+        return Ok(quote! { #member: #value });
+    }
+    let source_member: Member = match parse_attribute_string(&field.attrs, MAP, RENAME)? {
+        Some(rename) if field.ident.is_some() => Member::Named(format_ident!("{}", rename)),
+        Some(_) => panic!("{}", MAP_RENAME_REQUIRES_NAMED_FIELD),
+        None => member.clone(),
+    };
+    let try_with: Option<TokenStream2> = parse_attribute_value(&field.attrs, MAP, TRY_WITH);
+    let with: Option<TokenStream2> = parse_attribute_value(&field.attrs, MAP, WITH);
+    let value: TokenStream2 = match (try_with, with) {
+        (Some(try_with), _) if fallible => quote! { #try_with(source.#source_member)? },
+        (_, Some(with)) => quote! { #with(source.#source_member) },
+        (Some(_), None) => panic!("{}", MAP_TRY_WITH_REQUIRES_TRY_MAP_FROM),
+        _ => quote! { std::convert::Into::into(source.#source_member) },
+    };
+    Ok(quote! { #member: #value })
+}
+
+/// Generates `From` implementations for `#[map_from(...)]` sources and `TryFrom`
+/// implementations for `#[try_map_from(...)]` sources, mapping fields by name.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated conversion implementations.
+pub(crate) fn inner_map_from(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Data::Struct(data_struct) = &input.data else {
+        panic!("{}", UNSUPPORTED_MAP_FROM_DERIVE);
+    };
+    let sources: Vec<(Type, Option<Type>)> = collect_map_from_sources(&input.attrs, MAP_FROM);
+    let try_sources: Vec<(Type, Option<Type>)> =
+        collect_map_from_sources(&input.attrs, TRY_MAP_FROM);
+    if sources.is_empty() && try_sources.is_empty() {
+        panic!("{}", MISSING_MAP_FROM_SOURCE);
+    }
+    let mappings: syn::Result<Vec<TokenStream2>> = if sources.is_empty() {
+        Ok(Vec::new())
+    } else {
+        data_struct
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| build_field_mapping(field, index, false))
+            .collect()
+    };
+    let try_mappings: syn::Result<Vec<TokenStream2>> = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| build_field_mapping(field, index, true))
+        .collect();
+    let (mappings, try_mappings): (Vec<TokenStream2>, Vec<TokenStream2>) =
+        match (mappings, try_mappings) {
+            (Ok(mappings), Ok(try_mappings)) => (mappings, try_mappings),
+            (Err(error), _) | (_, Err(error)) => return error.to_compile_error().into(),
+        };
+    let mut expanded: TokenStream2 = TokenStream2::new();
+    for (source_ty, error) in sources {
+        if error.is_some() {
+            panic!("{}", MAP_FROM_ERROR_NOT_ALLOWED);
+        }
+        expanded.extend(quote! {
+            impl #impl_generics std::convert::From<#source_ty> for #name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn from(source: #source_ty) -> Self {
+                    Self { #(#mappings),* }
+                }
+            }
+        });
+    }
+    for (source_ty, error) in try_sources {
+        let error_ty: Type = error
+            .unwrap_or_else(|| parse_quote!(std::boxed::Box<dyn std::error::Error + Send + Sync>));
+        expanded.extend(quote! {
+            impl #impl_generics std::convert::TryFrom<#source_ty> for #name #ty_generics #where_clause {
+                type Error = #error_ty;
+
+                #[allow(unused_variables)]
+                fn try_from(source: #source_ty) -> Result<Self, Self::Error> {
+                    Ok(Self { #(#try_mappings),* })
+                }
+            }
+        });
+    }
+    expanded.into()
+}
@@ -0,0 +1,9 @@
+/// Error message indicating that #[derive(Merge)] is only supported for structs.
+pub(crate) const UNSUPPORTED_MERGE_DERIVE: &str = "#[derive(Merge)] is only supported for structs.";
+
+/// Error message indicating that a field declares an unknown or more than one merge strategy.
+pub(crate) const INVALID_MERGE_STRATEGY: &str = "Each field accepts a single #[merge(...)] strategy: overwrite, keep, option, append, nested or with = path";
+
+/// Error message indicating that #[merge(option)] was used on a field that is not an Option.
+pub(crate) const MERGE_OPTION_REQUIRES_OPTION: &str =
+    "#[merge(option)] requires an Option<T> field";
@@ -0,0 +1,26 @@
+use crate::*;
+
+/// Represents how a field of `other` is combined into `self` by a derived `Merge`.
+///
+/// # Variants
+/// - `Overwrite` - Replaces the field with the other value.
+/// - `Keep` - Keeps the current value and drops the other one.
+/// - `Option` - Replaces the field only when the other value is `Some`.
+/// - `Append` - Extends the field with the items of the other value.
+/// - `Nested` - Merges the other value into the field with its own `Merge` implementation.
+/// - `With` - Calls the given function with the field and the other value.
+#[derive(Clone)]
+pub(crate) enum MergeStrategy {
+    /// Replaces the field with the other value.
+    Overwrite,
+    /// Keeps the current value and drops the other one.
+    Keep,
+    /// Replaces the field only when the other value is `Some`.
+    Option,
+    /// Extends the field with the items of the other value.
+    Append,
+    /// Merges the other value into the field with its own `Merge` implementation.
+    Nested,
+    /// Calls the given function with the field and the other value.
+    With(TokenStream2),
+}
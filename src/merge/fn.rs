@@ -0,0 +1,110 @@
+use crate::*;
+
+/// Determines the merge strategy of a field from its `#[merge(...)]` attribute.
+///
+/// Fields without a strategy use `Option` when their type is `Option<T>` and `Overwrite` otherwise.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to analyze.
+///
+/// # Returns
+///
+/// - `MergeStrategy` - The strategy applied to the field.
+fn parse_merge_strategy(field: &Field) -> MergeStrategy {
+    let flags: Vec<String> = parse_attribute_flags(&field.attrs, MERGE);
+    let with: Option<TokenStream2> = parse_attribute_value(&field.attrs, MERGE, WITH);
+    if flags.len() + usize::from(with.is_some()) > 1 {
+        panic!("{}", INVALID_MERGE_STRATEGY);
+    }
+    if let Some(with) = with {
+        return MergeStrategy::With(with);
+    }
+    match flags.first().map(String::as_str) {
+        Some(OVERWRITE) => MergeStrategy::Overwrite,
+        Some(KEEP) => MergeStrategy::Keep,
+        Some(OPTION) => {
+            if !is_option_type(&field.ty) {
+                panic!("{}", MERGE_OPTION_REQUIRES_OPTION);
+            }
+            MergeStrategy::Option
+        }
+        Some(APPEND) => MergeStrategy::Append,
+        Some(NESTED) => MergeStrategy::Nested,
+        Some(_) => panic!("{}", INVALID_MERGE_STRATEGY),
+        None if is_option_type(&field.ty) => MergeStrategy::Option,
+        None => MergeStrategy::Overwrite,
+    }
+}
+
+/// Generates the statement merging a single field of `other` into `self`.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to merge.
+/// - `Member` - The field name or tuple index.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated statement.
+fn build_field_merge(field: &Field, member: Member) -> TokenStream2 {
+    match parse_merge_strategy(field) {
+        MergeStrategy::Overwrite => quote! {
+            self.#member = other.#member;
+        },
+        MergeStrategy::Keep => quote! {},
+        MergeStrategy::Option => quote! {
+            if other.#member.is_some() {
+                self.#member = other.#member;
+            }
+        },
+        MergeStrategy::Append => quote! {
+            std::iter::Extend::extend(&mut self.#member, other.#member);
+        },
+        MergeStrategy::Nested => quote! {
+            lombok_macros_runtime::Merge::merge(&mut self.#member, other.#member);
+        },
+        MergeStrategy::With(with) => quote! {
+            #with(&mut self.#member, other.#member);
+        },
+    }
+}
+
+/// Generates a `Merge` implementation combining two values field by field.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `Merge` implementation.
+pub(crate) fn inner_merge(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let merges: Vec<TokenStream2> = match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let member: Member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(Index::from(index)),
+                };
+                build_field_merge(field, member)
+            })
+            .collect(),
+        _ => panic!("{}", UNSUPPORTED_MERGE_DERIVE),
+    };
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics lombok_macros_runtime::Merge for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn merge(&mut self, other: Self) {
+                #(#merges)*
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,7 @@
+mod r#const;
+mod r#enum;
+mod r#fn;
+
+pub(crate) use r#const::*;
+pub(crate) use r#enum::*;
+pub(crate) use r#fn::*;
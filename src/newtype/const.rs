@@ -0,0 +1,16 @@
+/// Error message indicating that #[derive(Newtype)] is only supported for structs with fields.
+pub(crate) const UNSUPPORTED_NEWTYPE_DERIVE: &str =
+    "#[derive(Newtype)] is only supported for structs with at least one field.";
+
+/// Error message indicating that the wrapped field of a multi-field struct is not marked.
+pub(crate) const NEWTYPE_REQUIRES_DEREF_FIELD: &str =
+    "#[derive(Newtype)] on a struct with several fields requires exactly one #[deref] field";
+
+/// Error message indicating that several fields are marked as the wrapped field.
+pub(crate) const MULTIPLE_DEREF_FIELDS: &str = "#[derive(Newtype)] allows only one #[deref] field";
+
+/// Error message indicating that a `#[deref(...)]` option is not recognized.
+pub(crate) const UNKNOWN_DEREF_OPTION: &str = "Unknown #[deref(...)] option, expected `mut`";
+
+/// The `#[deref(mut)]` option enabling `DerefMut` and `AsMut`.
+pub(crate) const DEREF_MUT_OPTION: &str = "mut";
@@ -0,0 +1,172 @@
+use crate::*;
+
+/// Analyzes a field marked with `#[deref]` to find the wrapped value of a newtype.
+///
+/// # Arguments
+///
+/// - `&Field` - The field structure to analyze.
+/// - `usize` - The index of the field, used as its member for tuple fields.
+///
+/// # Returns
+///
+/// - `Option<(Member, Type, bool)>` - The field member, its type and whether `#[deref(mut)]` was given, None if unmarked.
+fn analyze_field_for_newtype(field: &Field, index: usize) -> Option<(Member, Type, bool)> {
+    if !field.attrs.iter().any(|attr| attr.path().is_ident(DEREF)) {
+        return None;
+    }
+    let mut is_mut: bool = false;
+    for flag in parse_attribute_flags(&field.attrs, DEREF) {
+        if flag != DEREF_MUT_OPTION {
+            panic!("{} `{}`.", UNKNOWN_DEREF_OPTION, flag);
+        }
+        is_mut = true;
+    }
+    let member: Member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    };
+    Some((member, field.ty.clone(), is_mut))
+}
+
+/// Finds the wrapped field of a newtype: the single field of the struct or the one marked with `#[deref]`.
+///
+/// # Arguments
+///
+/// - `&Fields` - The fields of the struct.
+///
+/// # Returns
+///
+/// - `(Member, Type, bool)` - The field member, its type and whether mutable access was requested.
+fn find_newtype_field(fields: &Fields) -> (Member, Type, bool) {
+    let mut marked: Vec<(Member, Type, bool)> = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, field)| analyze_field_for_newtype(field, index))
+        .collect();
+    if marked.len() > 1 {
+        panic!("{}", MULTIPLE_DEREF_FIELDS);
+    }
+    if let Some(target) = marked.pop() {
+        return target;
+    }
+    match fields.iter().collect::<Vec<&Field>>().as_slice() {
+        [field] => {
+            let member: Member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(0)),
+            };
+            (member, field.ty.clone(), false)
+        }
+        [] => panic!("{}", UNSUPPORTED_NEWTYPE_DERIVE),
+        _ => panic!("{}", NEWTYPE_REQUIRES_DEREF_FIELD),
+    }
+}
+
+/// Generates the expression building the struct from the wrapped value, defaulting every other field.
+///
+/// # Arguments
+///
+/// - `&Fields` - The fields of the struct.
+/// - `&Member` - The member of the wrapped field.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated constructor expression using `value` for the wrapped field.
+fn build_newtype_construction(fields: &Fields, target: &Member) -> TokenStream2 {
+    let values: Vec<TokenStream2> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member: Member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+            if &member == target {
+                quote! { #member: value }
+            } else {
+                quote! { #member: std::default::Default::default() }
+            }
+        })
+        .collect();
+    quote! {
+        Self { #(#values),* }
+    }
+}
+
+/// Generates `Deref`, `AsRef`, `Borrow`, `From` and `into_inner` for a newtype wrapper,
+/// plus `DerefMut` and `AsMut` when the field is marked `#[deref(mut)]`.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated trait implementations and `into_inner` method.
+pub(crate) fn inner_newtype(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Data::Struct(data_struct) = &input.data else {
+        panic!("{}", UNSUPPORTED_NEWTYPE_DERIVE);
+    };
+    let (member, inner_ty, is_mut): (Member, Type, bool) = find_newtype_field(&data_struct.fields);
+    let construction: TokenStream2 = build_newtype_construction(&data_struct.fields, &member);
+    let mut expanded: TokenStream2 = quote! {
+        impl #impl_generics std::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #inner_ty;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+
+        impl #impl_generics std::convert::AsRef<#inner_ty> for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn as_ref(&self) -> &#inner_ty {
+                &self.#member
+            }
+        }
+
+        impl #impl_generics std::borrow::Borrow<#inner_ty> for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn borrow(&self) -> &#inner_ty {
+                &self.#member
+            }
+        }
+
+        impl #impl_generics std::convert::From<#inner_ty> for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn from(value: #inner_ty) -> Self {
+                #construction
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[inline(always)]
+            #vis fn into_inner(self) -> #inner_ty {
+                self.#member
+            }
+        }
+    };
+    if is_mut {
+        expanded.extend(quote! {
+            impl #impl_generics std::ops::DerefMut for #name #ty_generics #where_clause {
+                #[inline(always)]
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.#member
+                }
+            }
+
+            impl #impl_generics std::convert::AsMut<#inner_ty> for #name #ty_generics #where_clause {
+                #[inline(always)]
+                fn as_mut(&mut self) -> &mut #inner_ty {
+                    &mut self.#member
+                }
+            }
+        });
+    }
+    expanded.into()
+}
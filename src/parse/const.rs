@@ -13,6 +13,90 @@ pub const DEBUG: &str = "debug";
 /// Constant for the "new" function type.
 pub const NEW: &str = "new";
 
+/// Constant for the "field_name" attribute.
+pub const FIELD_NAME: &str = "field_name";
+
+/// Constant for the "field_access" attribute.
+pub const FIELD_ACCESS: &str = "field_access";
+
+/// Constant for the "visit" attribute.
+pub const VISIT: &str = "visit";
+
+/// Constant for the "diff" attribute.
+pub const DIFF: &str = "diff";
+
+/// Constant for the "prop" attribute.
+pub const PROP: &str = "prop";
+
+/// Constant for the "getter_trait" attribute.
+pub const GETTER_TRAIT: &str = "getter_trait";
+
+/// Constant for the flag implementing an existing getter trait without declaring it.
+pub const IMPL_ONLY: &str = "impl_only";
+
+/// Constant for the container flag generating a split-borrow accessor.
+pub const SPLIT: &str = "split";
+
+/// Constant for the "view" attribute.
+pub const VIEW: &str = "view";
+
+/// Constant for the field list key of the view attribute.
+pub const FIELDS: &str = "fields";
+
+/// Constant for the "patch" attribute.
+pub const PATCH: &str = "patch";
+
+/// Constant for the derive list forwarded to a generated struct.
+pub const DERIVE: &str = "derive";
+
+/// Constant for the "merge" attribute.
+pub const MERGE: &str = "merge";
+
+/// Constant for the merge strategy replacing the field with the other value.
+pub const OVERWRITE: &str = "overwrite";
+
+/// Constant for the merge strategy keeping the current value.
+pub const KEEP: &str = "keep";
+
+/// Constant for the merge strategy taking the other value when it is `Some`.
+pub const OPTION: &str = "option";
+
+/// Constant for the merge strategy extending a collection with the other value.
+pub const APPEND: &str = "append";
+
+/// Constant for the merge strategy merging a nested `Merge` value.
+pub const NESTED: &str = "nested";
+
+/// Constant for the "builder" attribute.
+pub const BUILDER: &str = "builder";
+
+/// Constant for the flag flattening a nested struct into a constructor, builder or getters.
+pub const FLATTEN: &str = "flatten";
+
+/// Constant for the "env" attribute.
+pub const ENV: &str = "env";
+
+/// Constant for the variable name prefix key of the env attribute.
+pub const PREFIX: &str = "prefix";
+
+/// Constant for the variable name key of the env attribute.
+pub const NAME: &str = "name";
+
+/// Constant for the "data" attribute.
+pub const DATA: &str = "data";
+
+/// Constant for the "display" option.
+pub const DISPLAY: &str = "display";
+
+/// Constant for the "eq" option.
+pub const EQ: &str = "eq";
+
+/// Constant for the "hash" option.
+pub const HASH: &str = "hash";
+
+/// Constant for the "rename" attribute key.
+pub const RENAME: &str = "rename";
+
 /// Constant for the "skip" attribute.
 pub const SKIP: &str = "skip";
 
@@ -43,6 +127,72 @@ pub const COPY: &str = "copy";
 /// Constant for return deref type.
 pub const DEREF: &str = "deref";
 
+/// Constant for the getter flag returning the owned value of a Cow field.
+pub const INTO_OWNED: &str = "into_owned";
+
+/// Constant for the flag unwrapping `Cell`, `RefCell`, `Mutex` and `RwLock` fields in accessors.
+pub const UNWRAP: &str = "unwrap";
+
+/// Constant for the lazy getter initializer key.
+pub const LAZY: &str = "lazy";
+
+/// Constant for the atomic accessor ordering option.
+pub const ATOMIC: &str = "atomic";
+
+/// Constant for the flag generating `fetch_add_` and `increment_` helpers on atomic setters.
+pub const FETCH_ADD: &str = "fetch_add";
+
+/// Constant for the delegate attribute name.
+pub const DELEGATE: &str = "delegate";
+
+/// Constant for the delegated method list of a delegate attribute.
+pub const METHODS: &str = "methods";
+
+/// Constant for the delegated trait key of a delegate attribute.
+pub const TRAIT: &str = "trait";
+
+/// Constant for the infallible conversion source attribute name.
+pub const MAP_FROM: &str = "map_from";
+
+/// Constant for the fallible conversion source attribute name.
+pub const TRY_MAP_FROM: &str = "try_map_from";
+
+/// Constant for the field mapping attribute name.
+pub const MAP: &str = "map";
+
+/// Constant for the conversion function key of a field mapping.
+pub const WITH: &str = "with";
+
+/// Constant for the fallible conversion function key of a field mapping.
+pub const TRY_WITH: &str = "try_with";
+
+/// Constant for the default value key of a field attribute.
+pub const DEFAULT: &str = "default";
+
+/// Constant for the error type key of a fallible conversion attribute.
+pub const ERROR: &str = "error";
+
+/// Constant for the container options attribute name.
+pub const LOMBOK: &str = "lombok";
+
+/// Constant for the container flag using serde wire names.
+pub const SERDE_NAMES: &str = "serde_names";
+
+/// Constant for the serde attribute name.
+pub const SERDE: &str = "serde";
+
+/// Constant for the serde key renaming every field or variant of a container.
+pub const RENAME_ALL: &str = "rename_all";
+
+/// Constant for the serde key selecting the serialization name in `rename(serialize = "...")`.
+pub const SERIALIZE: &str = "serialize";
+
+/// Constant for the serde flag excluding a field from serialization only.
+pub const SKIP_SERIALIZING: &str = "skip_serializing";
+
+/// Constant for the computed getter list of a container attribute.
+pub const COMPUTED: &str = "computed";
+
 /// Constant for type specification.
 pub const CUSTOM_TYPE: &str = "type";
 
@@ -66,3 +216,12 @@ pub const OPEN_BRACKET: char = '<';
 
 /// Constant for closing angle bracket character.
 pub const CLOSE_BRACKET: char = '>';
+
+/// Constant for the equals sign separating attribute keys and values.
+pub const EQUAL: char = '=';
+
+/// Constant for the comma separating attribute arguments.
+pub const COMMA: char = ',';
+
+/// Error message indicating that an attribute value must be a string literal.
+pub(crate) const EXPECTED_STRING_LITERAL: &str = "expected string literal";
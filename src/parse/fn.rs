@@ -35,12 +35,36 @@ pub(crate) fn parse_tokens(tokens: TokenStream2, config: &mut Config) {
                         FuncType::New => {
                             config.skip_flags.insert(FuncType::New);
                         }
+                        FuncType::FieldName => {
+                            config.skip_flags.insert(FuncType::FieldName);
+                        }
+                        FuncType::FieldAccess => {
+                            config.skip_flags.insert(FuncType::FieldAccess);
+                        }
+                        FuncType::Visit => {
+                            config.skip_flags.insert(FuncType::Visit);
+                        }
+                        FuncType::Diff => {
+                            config.skip_flags.insert(FuncType::Diff);
+                        }
+                        FuncType::Prop => {
+                            config.skip_flags.insert(FuncType::Prop);
+                        }
+                        FuncType::Builder => {
+                            config.skip_flags.insert(FuncType::Builder);
+                        }
                         FuncType::Unknown => {
                             config.skip_flags.insert(FuncType::Get);
                             config.skip_flags.insert(FuncType::GetMut);
                             config.skip_flags.insert(FuncType::Set);
                             config.skip_flags.insert(FuncType::Debug);
                             config.skip_flags.insert(FuncType::New);
+                            config.skip_flags.insert(FuncType::FieldName);
+                            config.skip_flags.insert(FuncType::FieldAccess);
+                            config.skip_flags.insert(FuncType::Visit);
+                            config.skip_flags.insert(FuncType::Diff);
+                            config.skip_flags.insert(FuncType::Prop);
+                            config.skip_flags.insert(FuncType::Builder);
                         }
                     }
                 } else if ident_str == PUB {
@@ -61,6 +85,32 @@ pub(crate) fn parse_tokens(tokens: TokenStream2, config: &mut Config) {
                     }
                 } else if ident_str == PRIVATE {
                     config.visibility = Visibility::Private;
+                } else if ident_str == LAZY
+                    && let Some(TokenTree2::Punct(punct)) = tokens_iter.peek()
+                    && punct.as_char() == EQUAL
+                {
+                    tokens_iter.next();
+                    config.lazy_init = Some(parse_value_tokens(&mut tokens_iter));
+                } else if ident_str == ATOMIC {
+                    let mut ordering: TokenStream2 = TokenStream2::new();
+                    if let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                        && group.delimiter() == Delimiter::Parenthesis
+                    {
+                        ordering = group.stream();
+                        tokens_iter.next();
+                    }
+                    config.atomic_ordering = Some(ordering);
+                } else if ident_str == FLATTEN
+                    && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                    && group.delimiter() == Delimiter::Parenthesis
+                {
+                    tokens_iter.next();
+                } else if ident_str == FETCH_ADD {
+                    config.fetch_add = true;
+                } else if ident_str == UNWRAP {
+                    config.unwrap = true;
+                } else if ident_str == INTO_OWNED {
+                    config.return_type = ReturnType::IntoOwned;
                 } else if ident_str == CUSTOM_TYPE
                     && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
                     && group.delimiter() == Delimiter::Parenthesis
@@ -96,3 +146,306 @@ pub(crate) fn analyze_attributes(tokens: TokenStream2) -> Config {
     parse_tokens(tokens, &mut config);
     config
 }
+
+/// Collects the container-level atomic orderings from the `get` and `set` attributes of a struct.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the struct.
+///
+/// # Returns
+/// - `ContainerAtomicOrdering` - The load and store orderings declared on the container.
+pub(crate) fn parse_container_atomic_ordering(attrs: &[Attribute]) -> ContainerAtomicOrdering {
+    let mut ordering: ContainerAtomicOrdering = ContainerAtomicOrdering::default();
+    for attr in attrs {
+        if attr.path().is_ident(GET) {
+            ordering.load = parse_top_level_atomic_ordering(attr).or(ordering.load);
+        } else if attr.path().is_ident(SET) {
+            ordering.store = parse_top_level_atomic_ordering(attr).or(ordering.store);
+        }
+    }
+    ordering
+}
+
+/// Finds a top-level `atomic` or `atomic(Ordering)` argument of an attribute.
+///
+/// Only whole arguments are matched, so an `atomic` identifier inside a
+/// `computed(...)` expression or a `key = value` pair is ignored.
+///
+/// # Arguments
+/// - `&Attribute` - The `get` or `set` attribute of the struct.
+///
+/// # Returns
+/// - `Option<TokenStream2>` - The ordering tokens, empty for a bare `atomic`, or `None` if absent.
+fn parse_top_level_atomic_ordering(attr: &Attribute) -> Option<TokenStream2> {
+    let meta_list: &MetaList = attr.meta.require_list().ok()?;
+    let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+    let mut at_argument_start: bool = true;
+    while let Some(token) = tokens_iter.next() {
+        match &token {
+            TokenTree2::Punct(punct) if punct.as_char() == COMMA => {
+                at_argument_start = true;
+                continue;
+            }
+            TokenTree2::Ident(ident) if at_argument_start && ident == ATOMIC => {
+                match tokens_iter.peek() {
+                    None => return Some(TokenStream2::new()),
+                    Some(TokenTree2::Punct(punct)) if punct.as_char() == COMMA => {
+                        return Some(TokenStream2::new());
+                    }
+                    Some(TokenTree2::Group(group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        return Some(group.stream());
+                    }
+                    Some(_) => {}
+                }
+            }
+            _ => {}
+        }
+        at_argument_start = false;
+    }
+    None
+}
+
+/// Consumes the value tokens following a `key =` pair up to the next top-level comma.
+///
+/// # Arguments
+/// - `&mut Peekable<IntoIter>` - The token iterator positioned right after the `=`.
+///
+/// # Returns
+/// - `TokenStream2` - The consumed value tokens.
+pub(crate) fn parse_value_tokens(tokens_iter: &mut Peekable<IntoIter>) -> TokenStream2 {
+    let mut value: TokenStream2 = TokenStream2::new();
+    while let Some(value_token) = tokens_iter.peek() {
+        if let TokenTree2::Punct(punct) = value_token
+            && punct.as_char() == COMMA
+        {
+            break;
+        }
+        value.extend(tokens_iter.next());
+    }
+    value
+}
+
+/// Finds the tokens assigned to `key` inside the attributes named `attr_name`.
+///
+/// The value spans every token after `key =` up to the next top-level comma,
+/// so both literals (`rename = "id"`) and expressions (`default = 8080`) are supported.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes to search.
+/// - `&str` - The attribute name, such as `visit`.
+/// - `&str` - The key whose value should be returned, such as `rename`.
+///
+/// # Returns
+/// - `Option<TokenStream2>` - The value tokens of the first matching `key = value` pair.
+pub(crate) fn parse_attribute_value(
+    attrs: &[Attribute],
+    attr_name: &str,
+    key: &str,
+) -> Option<TokenStream2> {
+    for attr in attrs {
+        if !attr.path().is_ident(attr_name) {
+            continue;
+        }
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        while let Some(token) = tokens_iter.next() {
+            if let TokenTree2::Ident(ident) = &token
+                && ident == key
+                && let Some(TokenTree2::Punct(punct)) = tokens_iter.peek()
+                && punct.as_char() == EQUAL
+            {
+                tokens_iter.next();
+                return Some(parse_value_tokens(&mut tokens_iter));
+            }
+        }
+    }
+    None
+}
+
+/// Finds the string literal assigned to `key` inside the attributes named `attr_name`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes to search.
+/// - `&str` - The attribute name, such as `visit`.
+/// - `&str` - The key whose value should be returned, such as `rename`.
+///
+/// # Returns
+/// - `syn::Result<Option<String>>` - The literal value, `None` if the key is absent,
+///   or an error spanning the value if it is not a string literal.
+pub(crate) fn parse_attribute_string(
+    attrs: &[Attribute],
+    attr_name: &str,
+    key: &str,
+) -> syn::Result<Option<String>> {
+    let Some(value) = parse_attribute_value(attrs, attr_name, key) else {
+        return Ok(None);
+    };
+    parse2::<LitStr>(value.clone())
+        .map(|lit| Some(lit.value()))
+        .map_err(|_| syn::Error::new_spanned(value, EXPECTED_STRING_LITERAL))
+}
+
+/// Collects the bare flags, such as `new` in `#[data(new, eq)]`, of the attributes named `attr_name`.
+///
+/// Identifiers that are part of a `key = value` pair or followed by a group are not flags.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes to search.
+/// - `&str` - The attribute name, such as `data`.
+///
+/// # Returns
+/// - `Vec<String>` - The flags in declaration order.
+pub(crate) fn parse_attribute_flags(attrs: &[Attribute], attr_name: &str) -> Vec<String> {
+    let mut flags: Vec<String> = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident(attr_name) {
+            continue;
+        }
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        let mut at_argument_start: bool = true;
+        while let Some(token) = tokens_iter.next() {
+            match &token {
+                TokenTree2::Punct(punct) if punct.as_char() == COMMA => {
+                    at_argument_start = true;
+                    continue;
+                }
+                TokenTree2::Ident(ident) if at_argument_start => {
+                    let is_flag: bool = match tokens_iter.peek() {
+                        None => true,
+                        Some(TokenTree2::Punct(punct)) => punct.as_char() == COMMA,
+                        Some(_) => false,
+                    };
+                    if is_flag {
+                        flags.push(ident.to_string());
+                    }
+                }
+                _ => {}
+            }
+            at_argument_start = false;
+        }
+    }
+    flags
+}
+
+/// Checks whether the container opted into serde wire names with `#[lombok(serde_names)]`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the container.
+///
+/// # Returns
+/// - `bool` - True if the `serde_names` flag is present.
+pub(crate) fn has_serde_names(attrs: &[Attribute]) -> bool {
+    parse_attribute_flags(attrs, LOMBOK)
+        .iter()
+        .any(|flag| flag == SERDE_NAMES)
+}
+
+/// Finds the serialization value of a serde key written as `key = "..."` or `key(serialize = "...")`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes to search.
+/// - `&str` - The serde key, such as `rename`.
+///
+/// # Returns
+/// - `Option<String>` - The serialization value, or `None` if the key is absent.
+fn parse_serde_string(attrs: &[Attribute], key: &str) -> Option<String> {
+    if let Ok(Some(value)) = parse_attribute_string(attrs, SERDE, key) {
+        return Some(value);
+    }
+    for attr in attrs {
+        if !attr.path().is_ident(SERDE) {
+            continue;
+        }
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        while let Some(token) = tokens_iter.next() {
+            if let TokenTree2::Ident(ident) = &token
+                && ident == key
+                && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                && group.delimiter() == Delimiter::Parenthesis
+            {
+                let mut inner_iter: Peekable<IntoIter> = group.stream().into_iter().peekable();
+                while let Some(inner_token) = inner_iter.next() {
+                    if let TokenTree2::Ident(inner_ident) = &inner_token
+                        && inner_ident == SERIALIZE
+                        && let Some(TokenTree2::Punct(punct)) = inner_iter.peek()
+                        && punct.as_char() == EQUAL
+                    {
+                        inner_iter.next();
+                        return parse2::<LitStr>(parse_value_tokens(&mut inner_iter))
+                            .ok()
+                            .map(|lit| lit.value());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Finds the wire name declared with `#[serde(rename = "...")]` or `#[serde(rename(serialize = "..."))]`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the field or variant.
+///
+/// # Returns
+/// - `Option<String>` - The serde rename, or `None` if the name is not changed.
+pub(crate) fn parse_serde_rename(attrs: &[Attribute]) -> Option<String> {
+    parse_serde_string(attrs, RENAME)
+}
+
+/// Finds the rule declared with `#[serde(rename_all = "...")]` or `#[serde(rename_all(serialize = "..."))]`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the container or variant.
+///
+/// # Returns
+/// - `Option<String>` - The rename rule, such as `camelCase`, or `None` if absent.
+pub(crate) fn parse_serde_rename_all(attrs: &[Attribute]) -> Option<String> {
+    parse_serde_string(attrs, RENAME_ALL)
+}
+
+/// Resolves the serde wire name of a field or variant.
+///
+/// An explicit `rename` wins over the `rename_all` rule of the enclosing container.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the field or variant.
+/// - `&str` - The Rust name of the field or variant.
+/// - `Option<&str>` - The `rename_all` rule of the enclosing container or variant.
+/// - `bool` - Whether the name is a PascalCase variant name rather than a snake_case field name.
+///
+/// # Returns
+/// - `Option<String>` - The wire name, or `None` if serde keeps the Rust name.
+pub(crate) fn resolve_serde_name(
+    attrs: &[Attribute],
+    name: &str,
+    rename_all: Option<&str>,
+    is_variant: bool,
+) -> Option<String> {
+    parse_serde_rename(attrs).or_else(|| {
+        rename_all.map(|rule| apply_serde_rename_rule(&get_clean_attr_name(name), rule, is_variant))
+    })
+}
+
+/// Checks whether a field is excluded from serialization with `#[serde(skip)]` or `#[serde(skip_serializing)]`.
+///
+/// # Arguments
+/// - `&[Attribute]` - The attributes of the field.
+///
+/// # Returns
+/// - `bool` - True if serde never serializes the field.
+pub(crate) fn is_serde_skipped(attrs: &[Attribute]) -> bool {
+    parse_attribute_flags(attrs, SERDE)
+        .iter()
+        .any(|flag| flag == SKIP || flag == SKIP_SERIALIZING)
+}
@@ -0,0 +1,6 @@
+/// Error message indicating that #[derive(Patch)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_PATCH_DERIVE: &str =
+    "#[derive(Patch)] is only supported for structs with named fields.";
+
+/// Suffix of the patch struct name used when #[patch(name = "...")] is absent.
+pub(crate) const DEFAULT_PATCH_SUFFIX: &str = "Patch";
@@ -0,0 +1,163 @@
+use crate::*;
+
+/// Collects the derive lists declared with `#[patch(derive(...))]`.
+///
+/// # Arguments
+///
+/// - `&[Attribute]` - The container attributes of the struct.
+///
+/// # Returns
+///
+/// - `Vec<TokenStream2>` - The contents of every `derive(...)` group, in declaration order.
+fn collect_patch_derives(attrs: &[Attribute]) -> Vec<TokenStream2> {
+    let mut derives: Vec<TokenStream2> = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident(PATCH) {
+            continue;
+        }
+        let Ok(meta_list) = attr.meta.require_list() else {
+            continue;
+        };
+        let mut tokens_iter: Peekable<IntoIter> = meta_list.tokens.clone().into_iter().peekable();
+        while let Some(token) = tokens_iter.next() {
+            if let TokenTree2::Ident(ident) = &token
+                && ident == DERIVE
+                && let Some(TokenTree2::Group(group)) = tokens_iter.peek()
+                && group.delimiter() == Delimiter::Parenthesis
+            {
+                derives.push(group.stream());
+                tokens_iter.next();
+            }
+        }
+    }
+    derives
+}
+
+/// Collects the identifiers appearing in a token stream, including those of lifetimes and nested groups.
+///
+/// # Arguments
+///
+/// - `TokenStream2` - The tokens to scan.
+/// - `&mut Vec<String>` - The collected identifiers.
+fn collect_token_idents(tokens: TokenStream2, idents: &mut Vec<String>) {
+    for token in tokens {
+        match token {
+            TokenTree2::Ident(ident) => idents.push(ident.to_string()),
+            TokenTree2::Group(group) => collect_token_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// Checks whether a generic parameter of the struct appears in none of the patch field types.
+///
+/// # Arguments
+///
+/// - `&Generics` - The generics of the struct.
+/// - `&[&Type]` - The types of the patch fields.
+///
+/// # Returns
+///
+/// - `bool` - True if the patch struct needs a marker to use every generic parameter.
+fn has_unused_generic_param(generics: &Generics, field_tys: &[&Type]) -> bool {
+    let mut idents: Vec<String> = Vec::new();
+    for field_ty in field_tys {
+        collect_token_idents(field_ty.to_token_stream(), &mut idents);
+    }
+    generics.params.iter().any(|param| {
+        let param_ident: &Ident = match param {
+            GenericParam::Lifetime(lifetime_param) => &lifetime_param.lifetime.ident,
+            GenericParam::Type(type_param) => &type_param.ident,
+            GenericParam::Const(const_param) => &const_param.ident,
+        };
+        !idents.iter().any(|ident| param_ident == ident)
+    })
+}
+
+/// Generates a patch struct with optional fields and an `apply` method calling the setters.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated patch struct, its `Default` implementation and `apply`.
+pub(crate) fn inner_patch(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let generics: &Generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let patch_name: Ident = match parse_attribute_string(&input.attrs, PATCH, NAME) {
+        Err(error) => return error.to_compile_error().into(),
+        Ok(Some(patch_name)) => format_ident!("{}", patch_name),
+        Ok(None) => format_ident!("{}{}", name, DEFAULT_PATCH_SUFFIX),
+    };
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields
+                .named
+                .iter()
+                .filter(|field| !should_skip_field(field, FuncType::Set))
+                .collect(),
+            _ => panic!("{}", UNSUPPORTED_PATCH_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_PATCH_DERIVE),
+    };
+    let field_names: Vec<&Ident> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME))
+        .collect();
+    let field_tys: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let set_names: Vec<Ident> = field_names
+        .iter()
+        .map(|field_name| {
+            format_ident!(
+                "{}{}",
+                SET_METHOD_PREFIX,
+                get_clean_attr_name(&field_name.to_string())
+            )
+        })
+        .collect();
+    let derives: Vec<TokenStream2> = collect_patch_derives(&input.attrs);
+    let (marker_field, marker_value): (TokenStream2, TokenStream2) =
+        if has_unused_generic_param(generics, &field_tys) {
+            (
+                quote! { _marker: std::marker::PhantomData<fn() -> #name #ty_generics>, },
+                quote! { _marker: std::marker::PhantomData, },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+    let expanded: TokenStream2 = quote! {
+        #(#[derive(#derives)])*
+        #vis struct #patch_name #generics #where_clause {
+            #(#vis #field_names: Option<#field_tys>,)*
+            #marker_field
+        }
+
+        impl #impl_generics std::default::Default for #patch_name #ty_generics #where_clause {
+            #[inline(always)]
+            fn default() -> Self {
+                Self {
+                    #(#field_names: None,)*
+                    #marker_value
+                }
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            #vis fn apply(&mut self, patch: #patch_name #ty_generics) -> &mut Self {
+                #(
+                    if let Some(value) = patch.#field_names {
+                        self.#set_names(value);
+                    }
+                )*
+                self
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,5 @@
+mod r#const;
+mod r#fn;
+
+pub(crate) use r#const::*;
+pub(crate) use r#fn::*;
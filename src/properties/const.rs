@@ -0,0 +1,3 @@
+/// Error message indicating that #[derive(Properties)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_PROPERTIES_DERIVE: &str =
+    "#[derive(Properties)] is only supported for structs with named fields.";
@@ -0,0 +1,126 @@
+use crate::*;
+
+/// Generates the `to_map` insertion and the `from_map` initializer for a single field.
+///
+/// # Arguments
+///
+/// - `&Field` - The named field to convert.
+///
+/// # Returns
+///
+/// - `syn::Result<(TokenStream2, TokenStream2)>` - The insertion into the map and the `field: value` initializer.
+fn build_field_property(field: &Field) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let field_name: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+    let field_ty: &Type = &field.ty;
+    let default: Option<TokenStream2> =
+        parse_attribute_value(&field.attrs, PROP, DEFAULT).or_else(|| {
+            parse_attribute_flags(&field.attrs, PROP)
+                .iter()
+                .any(|flag| flag == DEFAULT)
+                .then(|| quote! { std::default::Default::default() })
+        });
+    if should_skip_field(field, FuncType::Prop) {
+        let value: TokenStream2 =
+            default.unwrap_or_else(|| quote! { std::default::Default::default() });
+        return Ok((quote! {}, quote! { #field_name: #value }));
+    }
+    let key: String = parse_attribute_string(&field.attrs, PROP, RENAME)?
+        .unwrap_or_else(|| get_clean_attr_name(&field_name.to_string()));
+    let parse_value = |value_ty: &Type| {
+        quote! {
+            <#value_ty as std::str::FromStr>::from_str(value).map_err(|error| {
+                lombok_macros_runtime::PropertyError::InvalidValue {
+                    key: #key,
+                    value: value.clone(),
+                    message: error.to_string(),
+                }
+            })?
+        }
+    };
+    if let Some(inner_ty) = extract_option_inner_type(field_ty) {
+        let parsed: TokenStream2 = parse_value(&inner_ty);
+        let missing: TokenStream2 = default.unwrap_or_else(|| quote! { None });
+        return Ok((
+            quote! {
+                if let Some(value) = &self.#field_name {
+                    map.insert(#key, std::string::ToString::to_string(value));
+                }
+            },
+            quote! {
+                #field_name: match map.get(#key) {
+                    Some(value) => Some(#parsed),
+                    None => #missing,
+                }
+            },
+        ));
+    }
+    let parsed: TokenStream2 = parse_value(field_ty);
+    let missing: TokenStream2 = default.unwrap_or_else(|| {
+        quote! {
+            return Err(lombok_macros_runtime::PropertyError::MissingProperty(#key))
+        }
+    });
+    Ok((
+        quote! {
+            map.insert(#key, std::string::ToString::to_string(&self.#field_name));
+        },
+        quote! {
+            #field_name: match map.get(#key) {
+                Some(value) => #parsed,
+                None => #missing,
+            }
+        },
+    ))
+}
+
+/// Generates `to_map` and `from_map` methods converting a struct to and from string properties.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `to_map` and `from_map` implementations.
+pub(crate) fn inner_properties(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let properties: syn::Result<Vec<(TokenStream2, TokenStream2)>> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields
+                .named
+                .iter()
+                .map(build_field_property)
+                .collect(),
+            _ => panic!("{}", UNSUPPORTED_PROPERTIES_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_PROPERTIES_DERIVE),
+    };
+    let (insertions, initializers): (Vec<TokenStream2>, Vec<TokenStream2>) = match properties {
+        Ok(properties) => properties.into_iter().unzip(),
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            #vis fn to_map(&self) -> std::collections::BTreeMap<&'static str, String> {
+                let mut map: std::collections::BTreeMap<&'static str, String> =
+                    std::collections::BTreeMap::new();
+                #(#insertions)*
+                map
+            }
+
+            #[allow(unused_variables)]
+            #vis fn from_map(
+                map: &std::collections::HashMap<String, String>,
+            ) -> Result<Self, lombok_macros_runtime::PropertyError> {
+                Ok(Self {
+                    #(#initializers),*
+                })
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,7 @@
+/// Error message indicating that #[derive(SmartDefault)] is only supported for structs and enums.
+pub(crate) const UNSUPPORTED_SMART_DEFAULT_DERIVE: &str =
+    "#[derive(SmartDefault)] is only supported for structs and enums.";
+
+/// Error message indicating that an enum does not mark exactly one default variant.
+pub(crate) const SMART_DEFAULT_REQUIRES_ONE_VARIANT: &str =
+    "#[derive(SmartDefault)] on an enum requires exactly one variant marked #[default]";
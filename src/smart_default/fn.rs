@@ -0,0 +1,74 @@
+use crate::*;
+
+/// Generates the initializer of a struct or variant, using each field's `#[default(expr)]`.
+///
+/// # Arguments
+///
+/// - `&Fields` - The fields to initialize.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated braces or parentheses with the default values.
+fn build_default_fields(fields: &Fields) -> TokenStream2 {
+    let values: Vec<TokenStream2> = fields.iter().map(generate_field_default).collect();
+    match fields {
+        Fields::Named(_) => {
+            let names: Vec<&Option<Ident>> = fields.iter().map(|field| &field.ident).collect();
+            quote! { { #(#names: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #(#values),* ) },
+        Fields::Unit => quote! {},
+    }
+}
+
+/// Generates a `Default` implementation using per-field `#[default(expr)]` values
+/// and, for enums, the variant marked `#[default]`.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `Default` implementation.
+pub(crate) fn inner_smart_default(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let generics: Generics =
+        add_type_param_bound(&input.generics, parse_quote!(std::default::Default));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body: TokenStream2 = match &input.data {
+        Data::Struct(data_struct) => {
+            let fields: TokenStream2 = build_default_fields(&data_struct.fields);
+            quote! { Self #fields }
+        }
+        Data::Enum(data_enum) => {
+            let default_variants: Vec<&Variant> = data_enum
+                .variants
+                .iter()
+                .filter(|variant| {
+                    variant
+                        .attrs
+                        .iter()
+                        .any(|attr| attr.path().is_ident(DEFAULT))
+                })
+                .collect();
+            let [variant] = default_variants.as_slice() else {
+                panic!("{}", SMART_DEFAULT_REQUIRES_ONE_VARIANT);
+            };
+            let variant_name: &Ident = &variant.ident;
+            let fields: TokenStream2 = build_default_fields(&variant.fields);
+            quote! { Self::#variant_name #fields }
+        }
+        Data::Union(_) => panic!("{}", UNSUPPORTED_SMART_DEFAULT_DERIVE),
+    };
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics std::default::Default for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn default() -> Self {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,12 @@
+/// Error message indicating that #[derive(TypedBuilder)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_TYPED_BUILDER_DERIVE: &str =
+    "#[derive(TypedBuilder)] is only supported for structs with named fields.";
+
+/// Suffix of the generated builder struct name.
+pub(crate) const BUILDER_STRUCT_SUFFIX: &str = "Builder";
+
+/// Prefix of the marker type parameter tracking whether a required field is set.
+pub(crate) const BUILDER_MARKER_PREFIX: &str = "__";
+
+/// The type parameter of the closure result taken by `#[builder(flatten)]` setters.
+pub(crate) const BUILDER_FLATTEN_PARAM: &str = "__FlattenedBuilder";
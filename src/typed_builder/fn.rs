@@ -0,0 +1,297 @@
+use crate::*;
+
+/// Copies the generics of the struct and appends one type parameter per marker.
+///
+/// # Arguments
+///
+/// - `&Generics` - The generics of the struct.
+/// - `&[&Ident]` - The marker type parameters to append.
+///
+/// # Returns
+///
+/// - `Generics` - The generics of a builder impl block or of the builder struct.
+fn with_marker_params(generics: &Generics, markers: &[&Ident]) -> Generics {
+    let mut generics: Generics = generics.clone();
+    for marker in markers {
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from((*marker).clone())));
+    }
+    generics
+}
+
+/// Generates the generics, parameter type and value expression of a builder setter.
+///
+/// Setters of `#[builder(flatten)]` fields take a closure receiving the field type's own builder,
+/// so the nested fields are set in place and checked at compile time like the outer ones.
+///
+/// # Arguments
+///
+/// - `&Type` - The type of the field.
+/// - `&Config` - The configuration parsed from the field's `#[builder(...)]` attribute.
+/// - `bool` - Whether the field is marked `#[builder(flatten)]`.
+///
+/// # Returns
+///
+/// - `(TokenStream2, TokenStream2, TokenStream2)` - The setter generics, parameter type and value.
+fn build_setter_param(
+    ty: &Type,
+    config: &Config,
+    is_flatten: bool,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    if is_flatten {
+        let flatten_param: Ident = format_ident!("{}", BUILDER_FLATTEN_PARAM);
+        return (
+            quote! { <#flatten_param: lombok_macros_runtime::Build<Output = #ty>> },
+            quote! {
+                impl FnOnce(<#ty as lombok_macros_runtime::TypedBuild>::Builder) -> #flatten_param
+            },
+            quote! {
+                lombok_macros_runtime::Build::build(
+                    val(<#ty as lombok_macros_runtime::TypedBuild>::builder()),
+                )
+            },
+        );
+    }
+    let param_type_override: Option<TokenStream2> =
+        resolve_param_type_override(ty, config.param_type_override.as_ref());
+    (
+        quote! {},
+        generate_param_type(ty, param_type_override.as_ref()),
+        generate_param_value(param_type_override.as_ref()),
+    )
+}
+
+/// Generates the default value of an optional or skipped builder field.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to analyze.
+///
+/// # Returns
+///
+/// - `Option<TokenStream2>` - The `#[builder(default = expr)]` expression, `Default::default()`
+///   for a bare `#[builder(default)]`, or None for a required field.
+fn parse_builder_default(field: &Field) -> Option<TokenStream2> {
+    parse_attribute_value(&field.attrs, BUILDER, DEFAULT).or_else(|| {
+        parse_attribute_flags(&field.attrs, BUILDER)
+            .iter()
+            .any(|flag| flag == DEFAULT)
+            .then(|| quote! { std::default::Default::default() })
+    })
+}
+
+/// Generates a typestate builder whose `build()` only exists once every required field is set.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated builder struct, its setters, `builder()` and `build()`.
+pub(crate) fn inner_typed_builder(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let builder_name: Ident = format_ident!("{}{}", name, BUILDER_STRUCT_SUFFIX);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let struct_args: Vec<TokenStream2> = generate_generic_args(&input.generics);
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields.named.iter().collect(),
+            _ => panic!("{}", UNSUPPORTED_TYPED_BUILDER_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_TYPED_BUILDER_DERIVE),
+    };
+    let mut required: Vec<(&Ident, &Type, Config, bool, Ident)> = Vec::new();
+    let mut optional: Vec<(&Ident, &Type, Config, bool, TokenStream2)> = Vec::new();
+    let mut skipped: Vec<(&Ident, TokenStream2)> = Vec::new();
+    for field in fields {
+        let field_name: &Ident = field.ident.as_ref().expect(FIELD_SHOULD_HAVE_A_NAME);
+        let default: Option<TokenStream2> = parse_builder_default(field);
+        if should_skip_field(field, FuncType::Builder) {
+            skipped.push((
+                field_name,
+                default.unwrap_or_else(|| quote! { std::default::Default::default() }),
+            ));
+            continue;
+        }
+        let config: Config = field
+            .attrs
+            .iter()
+            .map(|attr| analyze_attributes(attr.to_token_stream()))
+            .find(|config| config.func_type == FuncType::Builder)
+            .unwrap_or_default();
+        let is_flatten: bool = is_flatten_field(field, BUILDER);
+        match default {
+            Some(default) => optional.push((field_name, &field.ty, config, is_flatten, default)),
+            None => {
+                let marker: Ident = format_ident!(
+                    "{}{}",
+                    BUILDER_MARKER_PREFIX,
+                    to_pascal_case(&get_clean_attr_name(&field_name.to_string()))
+                );
+                required.push((field_name, &field.ty, config, is_flatten, marker));
+            }
+        }
+    }
+    let markers: Vec<&Ident> = required.iter().map(|(.., marker)| marker).collect();
+    let required_names: Vec<&Ident> = required
+        .iter()
+        .map(|(field_name, ..)| *field_name)
+        .collect();
+    let required_tys: Vec<&Type> = required.iter().map(|(_, ty, ..)| *ty).collect();
+    let optional_names: Vec<&Ident> = optional
+        .iter()
+        .map(|(field_name, ..)| *field_name)
+        .collect();
+    let optional_tys: Vec<&Type> = optional.iter().map(|(_, ty, ..)| *ty).collect();
+    let optional_defaults: Vec<&TokenStream2> =
+        optional.iter().map(|(.., default)| default).collect();
+    let skipped_names: Vec<&Ident> = skipped.iter().map(|(field_name, _)| *field_name).collect();
+    let skipped_defaults: Vec<&TokenStream2> = skipped.iter().map(|(_, default)| default).collect();
+    let unset: TokenStream2 = quote! { lombok_macros_runtime::BuilderUnset };
+    let unset_args: Vec<&TokenStream2> = markers.iter().map(|_| &unset).collect();
+    let set_args: Vec<TokenStream2> = required_tys
+        .iter()
+        .map(|ty| quote! { lombok_macros_runtime::BuilderSet<#ty> })
+        .collect();
+    let builder_generics: Generics = with_marker_params(&input.generics, &markers);
+    let (builder_impl_generics, _, _) = builder_generics.split_for_impl();
+    let marker_ty: TokenStream2 = quote! {
+        std::marker::PhantomData<fn() -> #name #ty_generics>
+    };
+    let required_setters: Vec<TokenStream2> = required
+        .iter()
+        .enumerate()
+        .map(|(index, (field_name, ty, config, is_flatten, _))| {
+            let other_markers: Vec<&Ident> = markers
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, marker)| *marker)
+                .collect();
+            let setter_generics: Generics = with_marker_params(&input.generics, &other_markers);
+            let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+            let self_args: Vec<TokenStream2> = markers
+                .iter()
+                .enumerate()
+                .map(|(marker_index, marker)| {
+                    if marker_index == index {
+                        unset.clone()
+                    } else {
+                        marker.to_token_stream()
+                    }
+                })
+                .collect();
+            let output_args: Vec<TokenStream2> = markers
+                .iter()
+                .enumerate()
+                .map(|(marker_index, marker)| {
+                    if marker_index == index {
+                        quote! { lombok_macros_runtime::BuilderSet<#ty> }
+                    } else {
+                        marker.to_token_stream()
+                    }
+                })
+                .collect();
+            let other_names: Vec<&Ident> = required_names
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, other_name)| *other_name)
+                .chain(optional_names.iter().copied())
+                .collect();
+            let setter_vis: TokenStream2 = config.visibility.to_token_stream();
+            let (setter_generics, param_type, value): (TokenStream2, TokenStream2, TokenStream2) =
+                build_setter_param(ty, config, *is_flatten);
+            quote! {
+                impl #setter_impl_generics #builder_name<#(#struct_args,)* #(#self_args),*> #where_clause {
+                    #[inline(always)]
+                    #setter_vis fn #field_name #setter_generics(
+                        self,
+                        val: #param_type,
+                    ) -> #builder_name<#(#struct_args,)* #(#output_args),*> {
+                        #builder_name {
+                            #field_name: lombok_macros_runtime::BuilderSet(#value),
+                            #(#other_names: self.#other_names,)*
+                            _marker: std::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        })
+        .collect();
+    let optional_setters: Vec<TokenStream2> = optional
+        .iter()
+        .map(|(field_name, ty, config, is_flatten, _)| {
+            let setter_vis: TokenStream2 = config.visibility.to_token_stream();
+            let (setter_generics, param_type, value): (TokenStream2, TokenStream2, TokenStream2) =
+                build_setter_param(ty, config, *is_flatten);
+            quote! {
+                #[inline(always)]
+                #setter_vis fn #field_name #setter_generics(mut self, val: #param_type) -> Self {
+                    self.#field_name = Some(#value);
+                    self
+                }
+            }
+        })
+        .collect();
+    let expanded: TokenStream2 = quote! {
+        #vis struct #builder_name #builder_impl_generics #where_clause {
+            #(#required_names: #markers,)*
+            #(#optional_names: Option<#optional_tys>,)*
+            _marker: #marker_ty,
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[inline(always)]
+            #vis fn builder() -> #builder_name<#(#struct_args,)* #(#unset_args),*> {
+                #builder_name {
+                    #(#required_names: lombok_macros_runtime::BuilderUnset,)*
+                    #(#optional_names: None,)*
+                    _marker: std::marker::PhantomData,
+                }
+            }
+        }
+
+        #(#required_setters)*
+
+        impl #builder_impl_generics #builder_name<#(#struct_args,)* #(#markers),*> #where_clause {
+            #(#optional_setters)*
+        }
+
+        impl #impl_generics #builder_name<#(#struct_args,)* #(#set_args),*> #where_clause {
+            #[inline(always)]
+            #vis fn build(self) -> #name #ty_generics {
+                #name {
+                    #(#required_names: self.#required_names.0,)*
+                    #(#optional_names: self.#optional_names.unwrap_or_else(|| #optional_defaults),)*
+                    #(#skipped_names: #skipped_defaults,)*
+                }
+            }
+        }
+
+        impl #impl_generics lombok_macros_runtime::TypedBuild for #name #ty_generics #where_clause {
+            type Builder = #builder_name<#(#struct_args,)* #(#unset_args),*>;
+
+            #[inline(always)]
+            fn builder() -> Self::Builder {
+                Self::builder()
+            }
+        }
+
+        impl #impl_generics lombok_macros_runtime::Build
+            for #builder_name<#(#struct_args,)* #(#set_args),*> #where_clause
+        {
+            type Output = #name #ty_generics;
+
+            #[inline(always)]
+            fn build(self) -> Self::Output {
+                self.build()
+            }
+        }
+    };
+    expanded.into()
+}
@@ -0,0 +1,10 @@
+/// Error message indicating that #[derive(Value)] is only supported for structs.
+pub(crate) const UNSUPPORTED_VALUE_DERIVE: &str = "#[derive(Value)] is only supported for structs.";
+
+/// Error message indicating that a #[derive(Value)] field carries a mutating accessor attribute.
+pub(crate) const VALUE_MUTATOR_NOT_ALLOWED: &str =
+    "#[derive(Value)] types are immutable, remove the #[set] and #[get_mut] attributes from field";
+
+/// Error message indicating that #[derive(PartialEq, Eq, Hash)] generation is only supported for structs.
+pub(crate) const UNSUPPORTED_EQ_HASH_DERIVE: &str =
+    "Equality and hash generation is only supported for structs.";
@@ -0,0 +1,131 @@
+use crate::*;
+
+/// Panics if any field carries a `#[set]` or `#[get_mut]` attribute.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+fn reject_value_mutators(input: &DeriveInput) {
+    let fields: &Fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        _ => panic!("{}", UNSUPPORTED_VALUE_DERIVE),
+    };
+    for (index, field) in fields.iter().enumerate() {
+        let has_mutator: bool = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(SET) || attr.path().is_ident(GET_MUT));
+        if has_mutator {
+            let field_name: String = match &field.ident {
+                Some(ident) => get_clean_attr_name(&ident.to_string()),
+                None => index.to_string(),
+            };
+            panic!("{} `{}`.", VALUE_MUTATOR_NOT_ALLOWED, field_name);
+        }
+    }
+}
+
+/// Collects the members of every struct field, used by the equality and hashing implementations.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+///
+/// # Returns
+///
+/// - `Vec<Member>` - The named or positional member of each field.
+fn collect_struct_members(input: &DeriveInput) -> Vec<Member> {
+    match &input.data {
+        Data::Struct(data_struct) => data_struct
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            })
+            .collect(),
+        _ => panic!("{}", UNSUPPORTED_EQ_HASH_DERIVE),
+    }
+}
+
+/// Generates `PartialEq` and `Eq` implementations comparing every struct field.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated `PartialEq` and `Eq` implementations.
+pub(crate) fn build_eq_impl(input: &DeriveInput) -> TokenStream2 {
+    let name: &Ident = &input.ident;
+    let generics: Generics = add_type_param_bound(&input.generics, parse_quote!(std::cmp::Eq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let members: Vec<Member> = collect_struct_members(input);
+    quote! {
+        impl #impl_generics std::cmp::PartialEq for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#members == other.#members)*
+            }
+        }
+
+        impl #impl_generics std::cmp::Eq for #name #ty_generics #where_clause {}
+    }
+}
+
+/// Generates a `Hash` implementation hashing every struct field.
+///
+/// # Arguments
+///
+/// - `&DeriveInput` - The derive input representing the struct.
+///
+/// # Returns
+///
+/// - `TokenStream2` - The generated `Hash` implementation.
+pub(crate) fn build_hash_impl(input: &DeriveInput) -> TokenStream2 {
+    let name: &Ident = &input.ident;
+    let generics: Generics = add_type_param_bound(&input.generics, parse_quote!(std::hash::Hash));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let members: Vec<Member> = collect_struct_members(input);
+    quote! {
+        impl #impl_generics std::hash::Hash for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                #(std::hash::Hash::hash(&self.#members, state);)*
+            }
+        }
+    }
+}
+
+/// Generates an immutable data class: getters, a constructor, `Debug`, `PartialEq`, `Eq` and `Hash`.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The combined generated implementations.
+pub(crate) fn inner_value(input: TokenStream) -> TokenStream {
+    let source: TokenStream = input.clone();
+    let derive_input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    reject_value_mutators(&derive_input);
+    let visibility: Visibility = parse_new_visibility(&derive_input);
+    let mut result: TokenStream2 = TokenStream2::new();
+    result.extend(TokenStream2::from(inner_lombok_data(
+        source.clone(),
+        true,
+        false,
+        false,
+    )));
+    result.extend(TokenStream2::from(inner_new_constructor(
+        &derive_input,
+        visibility,
+    )));
+    result.extend(TokenStream2::from(inner_custom_debug(source)));
+    result.extend(build_eq_impl(&derive_input));
+    result.extend(build_hash_impl(&derive_input));
+    result.into()
+}
@@ -0,0 +1,18 @@
+/// Error message indicating that #[derive(View)] is only supported for structs with named fields.
+pub(crate) const UNSUPPORTED_VIEW_DERIVE: &str =
+    "#[derive(View)] is only supported for structs with named fields.";
+
+/// Error message indicating that a #[view(...)] declaration could not be parsed.
+pub(crate) const INVALID_VIEW_DECLARATION: &str = "Invalid #[view(...)] declaration, expected `name = \"Name\", fields(a, b)` and an optional `mut`";
+
+/// Error message indicating that a #[view(fields(...))] entry names a field the struct does not have.
+pub(crate) const UNKNOWN_VIEW_FIELD: &str = "Unknown field in #[view(fields(...))]";
+
+/// The lifetime of the borrows held by a generated view.
+pub(crate) const VIEW_LIFETIME: &str = "'view";
+
+/// Prefix for the methods returning a view.
+pub(crate) const VIEW_METHOD_PREFIX: &str = "as_";
+
+/// Suffix for the methods returning a mutable view.
+pub(crate) const VIEW_MUT_METHOD_SUFFIX: &str = "_mut";
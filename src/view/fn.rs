@@ -0,0 +1,130 @@
+use crate::*;
+
+/// Generates the view struct and the method returning it for a single `#[view(...)]` declaration.
+///
+/// # Arguments
+///
+/// - `&ViewDeclaration` - The parsed view declaration.
+/// - `&DeriveInput` - The struct the view borrows from.
+/// - `&[&Field]` - The named fields of the struct.
+///
+/// # Returns
+///
+/// - `(TokenStream2, TokenStream2)` - The view struct and the method returning it.
+fn build_view(
+    view: &ViewDeclaration,
+    input: &DeriveInput,
+    fields: &[&Field],
+) -> (TokenStream2, TokenStream2) {
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let view_name: &Ident = &view.name;
+    let lifetime: Lifetime = Lifetime::new(VIEW_LIFETIME, Span::call_site());
+    let method_suffix: &str = if view.is_mut {
+        VIEW_MUT_METHOD_SUFFIX
+    } else {
+        ""
+    };
+    let method_name: Ident = format_ident!(
+        "{}{}{}",
+        VIEW_METHOD_PREFIX,
+        to_snake_case(&view_name.to_string()),
+        method_suffix
+    );
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut view_generics: Generics = input.generics.clone();
+    view_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+    );
+    let (view_impl_generics, _, _) = view_generics.split_for_impl();
+    let generic_args: Vec<TokenStream2> = generate_generic_args(&input.generics);
+    let view_fields: Vec<&Ident> = view.fields.iter().collect();
+    let view_tys: Vec<&Type> = view
+        .fields
+        .iter()
+        .map(|view_field| {
+            fields
+                .iter()
+                .find(|field| field.ident.as_ref() == Some(view_field))
+                .map(|field| &field.ty)
+                .unwrap_or_else(|| panic!("{}: {}", UNKNOWN_VIEW_FIELD, view_field))
+        })
+        .collect();
+    let (reference, receiver): (TokenStream2, TokenStream2) = if view.is_mut {
+        (quote! { &#lifetime mut }, quote! { &mut self })
+    } else {
+        (quote! { &#lifetime }, quote! { &self })
+    };
+    let borrow: TokenStream2 = if view.is_mut {
+        quote! { &mut }
+    } else {
+        quote! { & }
+    };
+    let (marker_field, marker_value): (TokenStream2, TokenStream2) =
+        if input.generics.params.is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            (
+                quote! { _marker: std::marker::PhantomData<#reference #name #ty_generics>, },
+                quote! { _marker: std::marker::PhantomData, },
+            )
+        };
+    let view_struct: TokenStream2 = quote! {
+        #vis struct #view_name #view_impl_generics #where_clause {
+            #(#vis #view_fields: #reference #view_tys,)*
+            #marker_field
+        }
+    };
+    let method: TokenStream2 = quote! {
+        #[inline(always)]
+        #vis fn #method_name(#receiver) -> #view_name<'_, #(#generic_args),*> {
+            #view_name {
+                #(#view_fields: #borrow self.#view_fields,)*
+                #marker_value
+            }
+        }
+    };
+    (view_struct, method)
+}
+
+/// Generates borrowed view structs over subsets of a struct's fields.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated view structs and the methods returning them.
+pub(crate) fn inner_view(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields.named.iter().collect(),
+            _ => panic!("{}", UNSUPPORTED_VIEW_DERIVE),
+        },
+        _ => panic!("{}", UNSUPPORTED_VIEW_DERIVE),
+    };
+    let (view_structs, methods): (Vec<TokenStream2>, Vec<TokenStream2>) = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(VIEW))
+        .map(|attr| {
+            let view: ViewDeclaration = attr
+                .parse_args()
+                .unwrap_or_else(|error| panic!("{}: {}", INVALID_VIEW_DECLARATION, error));
+            build_view(&view, &input, &fields)
+        })
+        .unzip();
+    let expanded: TokenStream2 = quote! {
+        #(#view_structs)*
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    };
+    expanded.into()
+}
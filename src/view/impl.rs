@@ -0,0 +1,45 @@
+use crate::*;
+
+/// Implements parsing of the options listed in `#[view(...)]`.
+impl Parse for ViewDeclaration {
+    /// Parses a `ViewDeclaration` from the given parse stream.
+    ///
+    /// # Arguments
+    /// - `ParseStream` - The stream to parse from.
+    ///
+    /// # Returns
+    /// - `syn::Result<ViewDeclaration>` - The parsed declaration or a parse error.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name: Option<Ident> = None;
+        let mut fields: Vec<Ident> = Vec::new();
+        let mut is_mut: bool = false;
+        while !input.is_empty() {
+            if input.parse::<Option<Token![mut]>>()?.is_some() {
+                is_mut = true;
+            } else {
+                let key: Ident = input.parse()?;
+                if key == NAME {
+                    input.parse::<Token![=]>()?;
+                    name = Some(input.parse::<LitStr>()?.parse()?);
+                } else if key == FIELDS {
+                    let content: ParseBuffer;
+                    parenthesized!(content in input);
+                    fields = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect();
+                } else {
+                    return Err(syn::Error::new(key.span(), INVALID_VIEW_DECLARATION));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        let name: Ident = name.ok_or_else(|| input.error(INVALID_VIEW_DECLARATION))?;
+        Ok(ViewDeclaration {
+            name,
+            fields,
+            is_mut,
+        })
+    }
+}
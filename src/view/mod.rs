@@ -0,0 +1,8 @@
+mod r#const;
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub(crate) use r#const::*;
+pub(crate) use r#fn::*;
+pub(crate) use r#struct::*;
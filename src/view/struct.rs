@@ -0,0 +1,17 @@
+use crate::*;
+
+/// Represents a view declared with `#[view(name = "Name", fields(a, b))]`.
+///
+/// # Fields
+/// - `name` - The name of the generated view struct.
+/// - `fields` - The fields borrowed by the view, in declaration order.
+/// - `is_mut` - Whether the view holds `&mut` borrows instead of shared ones.
+#[derive(Clone)]
+pub(crate) struct ViewDeclaration {
+    /// The name of the generated view struct.
+    pub(crate) name: Ident,
+    /// The fields borrowed by the view, in declaration order.
+    pub(crate) fields: Vec<Ident>,
+    /// Whether the view holds `&mut` borrows instead of shared ones.
+    pub(crate) is_mut: bool,
+}
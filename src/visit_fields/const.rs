@@ -0,0 +1,3 @@
+/// Error message indicating that #[derive(VisitFields)] is not supported for unions.
+pub(crate) const UNSUPPORTED_VISIT_FIELDS_DERIVE: &str =
+    "#[derive(VisitFields)] is only supported for structs and enums.";
@@ -0,0 +1,143 @@
+use crate::*;
+
+/// Resolves the name a field is reported under, honoring `#[visit(skip)]` and `#[visit(rename = "...")]`.
+///
+/// # Arguments
+///
+/// - `&Field` - The field to resolve.
+/// - `usize` - The index of the field, used as its name for tuple fields.
+///
+/// # Returns
+///
+/// - `syn::Result<Option<String>>` - The visited name, or `None` if the field is skipped.
+fn resolve_visit_name(field: &Field, index: usize) -> syn::Result<Option<String>> {
+    if should_skip_field(field, FuncType::Visit) {
+        return Ok(None);
+    }
+    if let Some(rename) = parse_attribute_string(&field.attrs, VISIT, RENAME)? {
+        return Ok(Some(rename));
+    }
+    Ok(Some(match &field.ident {
+        Some(ident) => get_clean_attr_name(&ident.to_string()),
+        None => index.to_string(),
+    }))
+}
+
+/// Generates the visit calls for the fields of a struct accessed through `self`.
+///
+/// # Arguments
+///
+/// - `&Fields` - The fields of the struct.
+///
+/// # Returns
+///
+/// - `syn::Result<Vec<TokenStream2>>` - One `visitor.visit(...)` call per non-skipped field.
+fn build_struct_visits(fields: &Fields) -> syn::Result<Vec<TokenStream2>> {
+    let mut visits: Vec<TokenStream2> = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let Some(visit_name) = resolve_visit_name(field, index)? else {
+            continue;
+        };
+        let member: Member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        visits.push(quote! {
+            visitor.visit(#visit_name, &self.#member);
+        });
+    }
+    Ok(visits)
+}
+
+/// Generates the match arm visiting the fields of a single enum variant.
+///
+/// # Arguments
+///
+/// - `&Ident` - The name of the enum.
+/// - `&Variant` - The variant to generate the arm for.
+///
+/// # Returns
+///
+/// - `syn::Result<TokenStream2>` - The generated match arm.
+fn build_variant_visit_arm(name: &Ident, variant: &Variant) -> syn::Result<TokenStream2> {
+    let variant_name: &Ident = &variant.ident;
+    let variant_name_str: String = variant_name.to_string();
+    let mut bindings: Vec<TokenStream2> = Vec::new();
+    let mut visits: Vec<TokenStream2> = Vec::new();
+    for (index, field) in variant.fields.iter().enumerate() {
+        let binding: Ident = format_ident!("__field_{}", index);
+        match resolve_visit_name(field, index)? {
+            Some(visit_name) => {
+                visits.push(quote! {
+                    visitor.visit(#visit_name, #binding);
+                });
+                bindings.push(match &field.ident {
+                    Some(ident) => quote! { #ident: #binding },
+                    None => quote! { #binding },
+                });
+            }
+            None if field.ident.is_none() => bindings.push(quote! { _ }),
+            None => {}
+        }
+    }
+    let pattern: TokenStream2 = match &variant.fields {
+        Fields::Named(_) => quote! { #name::#variant_name { #(#bindings,)* .. } },
+        Fields::Unnamed(_) => quote! { #name::#variant_name(#(#bindings),*) },
+        Fields::Unit => quote! { #name::#variant_name },
+    };
+    Ok(quote! {
+        #pattern => {
+            visitor.visit_variant(#variant_name_str);
+            #(#visits)*
+        }
+    })
+}
+
+/// Generates a `visit_fields` method that reports every non-skipped field to a `FieldVisitor`.
+///
+/// # Arguments
+///
+/// - `TokenStream` - The input token stream to parse.
+///
+/// # Returns
+///
+/// - `TokenStream` - The generated `visit_fields` implementation.
+pub(crate) fn inner_visit_fields(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let name: &Ident = &input.ident;
+    let vis: &syn::Visibility = &input.vis;
+    let generics: Generics = add_type_param_bound(&input.generics, parse_quote!(std::fmt::Debug));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body: syn::Result<TokenStream2> = match &input.data {
+        Data::Struct(data_struct) => {
+            build_struct_visits(&data_struct.fields).map(|visits| quote! { #(#visits)* })
+        }
+        Data::Enum(data_enum) if data_enum.variants.is_empty() => Ok(quote! { match *self {} }),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| build_variant_visit_arm(name, variant))
+            .collect::<syn::Result<Vec<TokenStream2>>>()
+            .map(|arms| {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }),
+        Data::Union(_) => panic!("{}", UNSUPPORTED_VISIT_FIELDS_DERIVE),
+    };
+    let body: TokenStream2 = match body {
+        Ok(body) => body,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            #vis fn visit_fields<V: lombok_macros_runtime::FieldVisitor>(&self, visitor: &mut V) {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}